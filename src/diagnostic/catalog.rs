@@ -0,0 +1,240 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::message::Message;
+use crate::utils::span::Span;
+use std::collections::HashMap;
+use std::env::{current_exe, var_os};
+use std::fs::read_to_string;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+pub const ENGLISH: &[(&str, &str)] = &[
+    ("severity-error", "error"),
+    ("severity-warning", "warning"),
+    ("severity-note", "note"),
+    ("label-help", "help"),
+
+    ("applicability-machine-applicable", "machine-applicable"),
+    ("applicability-maybe-incorrect", "maybe incorrect"),
+    ("applicability-has-placeholders", "has placeholders"),
+    ("applicability-unspecified", "unspecified"),
+    ("edit-insert", "insert {text}"),
+    ("edit-remove", "remove {length} byte(s)"),
+    ("edit-replace", "replace {length} byte(s) with {text}"),
+
+    ("lexer-unexpected-dot", "unexpected '.'"),
+    ("lexer-remove-extra-dot", "remove the extra '.'"),
+    ("lexer-unterminated-string", "unterminated string"),
+    ("lexer-close-string", "close the string"),
+    ("lexer-empty-char", "empty character literal"),
+    ("lexer-unterminated-char", "unterminated character literal"),
+    ("lexer-close-char", "close the character literal"),
+    ("lexer-unterminated-comment", "unterminated sequence"),
+    ("lexer-close-comment", "close the block comment"),
+    ("parser-expected-expression", "expected an expression, found {found}"),
+    ("parser-expected-end", "expected end of file, found {found}"),
+    ("parser-expected-token", "expected '{token}', found {found}"),
+    ("parser-expected-item", "expected an item, found {found}"),
+    ("parser-expected-identifier", "expected an identifier, found {found}"),
+    ("parser-expected-pattern", "expected a pattern, found {found}"),
+    ("parser-expected-type", "expected a type, found {found}"),
+    ("parser-insert-semicolon", "add a ';' here"),
+    ("parser-invalid-assignment", "invalid left-hand side of assignment"),
+    ("parser-unclosed-group", "unclosed '('"),
+    ("parser-close-group", "close the parenthesis"),
+    ("parser-unclosed-block", "unclosed '{'"),
+    ("parser-close-block", "close the block"),
+    ("parser-found-token", "'{token}'"),
+    ("parser-found-end", "end of file"),
+
+    ("attribute-malformed", "malformed attribute, expected '#[level(lint, ...)]'"),
+    ("attribute-unknown", "unknown attribute, expected one of 'allow', 'warn' or 'deny': {name}"),
+
+    ("lint-unknown", "unknown lint: {name}"),
+    ("lint-trailing-dot-float", "float literal ends with '.'"),
+    ("lint-add-trailing-zero", "add a trailing zero"),
+    ("lint-leading-dot-float", "float literal starts with '.'"),
+    ("lint-add-leading-zero", "add a leading zero"),
+    ("lint-misspelled-keyword", "'{name}' looks like a misspelled keyword"),
+    ("lint-misspelled-name", "cannot find '{name}' in this scope"),
+
+    ("help-did-you-mean", "did you mean '{name}'?"),
+
+    ("fix-overlapping", "refusing to apply overlapping suggestion: {suggestion}"),
+    ("fix-applied", "{path}: applied {count} fix(es)"),
+
+    ("io-file-not-found", "could not find file: {path}"),
+    ("io-broken-symlink", "could not find file: {path} is a symbolic link to missing {target}"),
+    ("io-file-not-regular", "file not regular: {path}"),
+    ("io-file-not-readable", "file not readable: {path}: {reason}"),
+    ("io-file-not-writable", "file not writable: {path}: {reason}"),
+    ("io-directory-not-creatable", "could not create directory: {path}: {reason}"),
+    ("io-permission-denied", "permission denied: {path}"),
+    ("io-not-utf8", "file is not valid UTF-8: {path} (byte {offset})"),
+    ("io-file-too-large", "file too large: {path} is {size} bytes, the limit is {limit} (--max-file-size)"),
+
+    ("cli-missing-value", "missing value for '{flag}'"),
+    ("cli-invalid-value", "invalid value for '{flag}': {value}"),
+    ("cli-unknown-flag", "unknown flag: {flag}"),
+    ("cli-unexpected-value", "'{flag}' does not take a value"),
+    ("cli-unknown-unstable", "unknown unstable option: {option}"),
+    ("cli-unknown-artifact", "unknown artifact: {artifact}"),
+    ("cli-output-multiple-artifacts", "'-o' can only be used when emitting a single artifact"),
+    ("emit-output-multiple-inputs", "'-o' can only be used with a single input file"),
+    ("emit-unsupported", "cannot emit {artifact}: {reason}"),
+    ("emit-duplicate-output", "cannot emit {artifact}: {path} is the output of more than one input"),
+    ("cli-missing-command", "missing command"),
+    ("cli-unknown-command", "unknown command: {command}"),
+    ("cli-missing-file", "'soyc {command}' needs an input file"),
+    ("cli-too-many-files", "'soyc {command}' takes a single input file"),
+    ("driver-no-main", "'main' function not found in {path}"),
+    ("driver-add-main", "the entry file must define 'fn main() { ... }'"),
+    ("driver-main-parameters", "'main' cannot take parameters"),
+    ("driver-unsupported", "'soyc {command}' is not supported yet: {reason}"),
+    ("cli-unknown-lint", "unknown lint: {name}"),
+    ("cli-unknown-language", "could not find a message catalogue for language: {language}"),
+    ("cli-malformed-catalogue", "malformed message catalogue line: {line}"),
+
+    ("toml-unexpected", "expected {expected}, found {found}"),
+    ("toml-unterminated-string", "unterminated string"),
+    ("toml-unsupported-value", "unsupported value: {value}"),
+    ("toml-duplicate-key", "duplicate key: {key}"),
+
+    ("manifest-not-found", "could not find soy.toml in {directory} or any parent directory"),
+    ("manifest-missing-key", "missing manifest key: {key}"),
+    ("manifest-wrong-type", "manifest key '{key}' must be a {expected}"),
+    ("manifest-unknown-key", "unused manifest key: {key}"),
+    ("manifest-unknown-edition", "unknown edition: {edition}"),
+    ("manifest-unknown-profile", "unknown profile: {profile}"),
+    ("package-compiling", "compiling {name} v{version} ({profile})"),
+
+    ("watch-waiting", "watching {count} path(s) for changes, press ctrl-c to stop"),
+
+    ("log-invalid-directive", "ignoring invalid log directive: {directive}"),
+
+    ("ice-crashed", "internal compiler error: {message}"),
+    ("ice-unknown-payload", "unknown panic payload"),
+    ("ice-phase", "soyc {version} crashed while {phase} ({input})"),
+    ("ice-location", "panicked at {location}"),
+    ("ice-report", "this is a bug in soyc, please report it along with the input file"),
+    ("ice-bundle-hint", "pass --crash-bundle <directory> to write a reproduction bundle"),
+    ("ice-bundle-written", "crash bundle written to {path}"),
+    ("ice-bundle-failed", "could not write crash bundle: {reason}"),
+
+    ("engine-error-limit", "stopping after {count} error(s) (--error-limit)"),
+    ("summary-warning-one", "1 warning emitted"),
+    ("summary-warning-other", "{count} warnings emitted"),
+    ("summary-error-one", "aborting due to 1 error"),
+    ("summary-error-other", "aborting due to {count} errors"),
+    ("summary-errors-and-warnings", "{errors}; {warnings}")
+];
+
+pub struct Catalog {
+    pub language: String,
+    pub entries: HashMap<String, String>
+}
+
+static ACTIVE: OnceLock<Catalog> = OnceLock::new();
+
+fn invalid(message: Message) -> diagnostic::Diagnostic {
+    diagnostic::Diagnostic::new(
+        diagnostic::Kind::InvalidArgument,
+        Span::new(String::from("cli"), 1, 1, 0, 0),
+        message
+    )
+}
+
+impl Catalog {
+    pub fn parse(language: String, content: &str) -> Result<Self, diagnostic::Diagnostic> {
+        let mut entries = HashMap::new();
+
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match line.split_once('=') {
+                Some((id, text)) => entries.insert(String::from(id.trim()), String::from(text.trim())),
+                None => return Err(invalid(Message::new("cli-malformed-catalogue").with("line", line)))
+            };
+        }
+
+        Ok(Self {
+            language,
+            entries
+        })
+    }
+
+    pub fn locate(language: &str) -> Option<PathBuf> {
+        if language.ends_with(".catalog") {
+            return Some(PathBuf::from(language));
+        }
+
+        let file = format!("{}.catalog", language);
+
+        let mut directories = Vec::new();
+        directories.extend(var_os("SOYC_LOCALES").map(PathBuf::from));
+        directories.extend(current_exe().ok().and_then(|exe| exe.parent().map(|parent| parent.join("locales"))));
+        directories.push(PathBuf::from("locales"));
+
+        directories.into_iter().map(|directory| directory.join(&file)).find(|path| path.is_file())
+    }
+
+    pub fn load(language: &str) -> Result<Self, diagnostic::Diagnostic> {
+        let unknown = || invalid(Message::new("cli-unknown-language").with("language", language));
+
+        let path = Self::locate(language).ok_or_else(unknown)?;
+        let content = read_to_string(&path).map_err(|_| unknown())?;
+
+        Self::parse(String::from(language), &content)
+    }
+
+    pub fn lookup(&self, id: &str) -> Option<&str> {
+        self.entries.get(id).map(String::as_str)
+    }
+}
+
+pub fn activate(language: &str) -> Result<(), diagnostic::Diagnostic> {
+    if language == "en" {
+        return Ok(());
+    }
+
+    let _ = ACTIVE.set(Catalog::load(language)?);
+    Ok(())
+}
+
+pub fn render(message: &Message) -> String {
+    let template = ACTIVE
+        .get()
+        .and_then(|catalog| catalog.lookup(message.id))
+        .or_else(|| ENGLISH.iter().find(|(id, _)| *id == message.id).map(|(_, text)| *text));
+
+    let Some(template) = template else {
+        let arguments = message.arguments.iter().map(|(name, value)| format!(" {}={}", name, value)).collect::<String>();
+        return format!("{}{}", message.id, arguments);
+    };
+
+    let mut text = String::new();
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        text.push_str(&rest[..open]);
+        rest = &rest[open + 1..];
+
+        let value = rest.find('}').and_then(|close| {
+            let name = &rest[..close];
+            message.arguments.iter().find(|(argument, _)| *argument == name).map(|(_, value)| (value, close))
+        });
+
+        match value {
+            Some((value, close)) => {
+                text.push_str(value);
+                rest = &rest[close + 1..];
+            },
+
+            None => text.push('{')
+        }
+    }
+
+    text.push_str(rest);
+    text
+}
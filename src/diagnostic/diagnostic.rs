@@ -1,31 +1,128 @@
 use crate::utils::span::Span;
+use crate::diagnostic::suggestion::Suggestion;
+use crate::diagnostic::lint::Lint;
+use crate::diagnostic::message::Message;
 use std::fmt::{Display, Formatter};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Kind {
     FileNotFound,
     FileNotRegular,
     FileNotReadable,
-    SyntaxError
+    FileNotWritable,
+    FilePermissionDenied,
+    FileNotUtf8,
+    FileTooLarge,
+    SyntaxError,
+    ConflictingSuggestion,
+    InvalidArgument,
+    Unsupported,
+    ManifestError,
+    Lint
 }
 
+#[derive(Debug, Clone)]
 pub struct Diagnostic {
+    pub severity: Severity,
     pub kind: Kind,
     pub span: Span,
-    pub message: String
+    pub message: Message,
+    pub suggestions: Vec<Suggestion>,
+    pub helps: Vec<Message>,
+    pub lint: Option<&'static str>
+}
+
+impl Kind {
+    pub fn is_io(&self) -> bool {
+        matches!(
+            self,
+            Kind::FileNotFound
+                | Kind::FileNotRegular
+                | Kind::FileNotReadable
+                | Kind::FileNotWritable
+                | Kind::FilePermissionDenied
+                | Kind::FileNotUtf8
+                | Kind::FileTooLarge
+        )
+    }
 }
 
 impl Diagnostic {
-    pub fn new(kind: Kind, span: Span, message: String) -> Self {
+    pub fn new(kind: Kind, span: Span, message: Message) -> Self {
         Self {
+            severity: Severity::Error,
             kind,
             span,
-            message
+            message,
+            suggestions: Vec::new(),
+            helps: Vec::new(),
+            lint: None
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_lint(mut self, lint: &'static Lint) -> Self {
+        self.severity = Severity::Warning;
+        self.lint = Some(lint.name);
+        self
+    }
+
+    pub fn with_help(mut self, help: Message) -> Self {
+        self.helps.push(help);
+        self
+    }
+
+    pub fn with_optional_help(self, help: Option<Message>) -> Self {
+        match help {
+            Some(help) => self.with_help(help),
+            None => self
+        }
+    }
+
+    pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+        self.suggestions.push(suggestion);
+        self
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "{}", Message::new("severity-error")),
+            Severity::Warning => write!(f, "{}", Message::new("severity-warning")),
+            Severity::Note => write!(f, "{}", Message::new("severity-note"))
         }
     }
 }
 
 impl Display for Diagnostic {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{}[{}:{}]: {}", self.span.stream, self.span.row, self.span.column, self.message)
+        write!(f, "{}[{}:{}]: {}: {}", self.span.stream, self.span.row, self.span.column, self.severity, self.message)?;
+
+        match self.lint {
+            Some(lint) => writeln!(f, " [{}]", lint)?,
+            None => writeln!(f)?
+        }
+
+        for help in &self.helps {
+            writeln!(f, "    {}: {}", Message::new("label-help"), help)?;
+        }
+
+        for suggestion in &self.suggestions {
+            write!(f, "{}", suggestion)?;
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,166 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::lint;
+use crate::diagnostic::message::Message;
+use crate::driver::exit;
+use crate::utils::log;
+use std::collections::HashSet;
+
+type Key = (diagnostic::Severity, diagnostic::Kind, crate::utils::span::Span, Message);
+
+pub struct DiagnosticEngine {
+    pub error_limit: Option<usize>,
+    pub warnings_as_errors: bool,
+    pub allow_warnings: bool,
+    pub lints: lint::LintLevels,
+    pub quiet: bool,
+    pub errors: usize,
+    pub warnings: usize,
+    pub notes: usize,
+    pub diagnostics: Vec<diagnostic::Diagnostic>,
+    seen: HashSet<Key>,
+    io_failed: bool,
+    aborted: bool
+}
+
+impl DiagnosticEngine {
+    pub fn new(error_limit: Option<usize>, warnings_as_errors: bool) -> Self {
+        Self {
+            error_limit,
+            warnings_as_errors,
+            allow_warnings: false,
+            lints: lint::LintLevels::default(),
+            quiet: false,
+            errors: 0,
+            warnings: 0,
+            notes: 0,
+            diagnostics: Vec::new(),
+            seen: HashSet::new(),
+            io_failed: false,
+            aborted: false
+        }
+    }
+
+    pub fn quiet(lints: lint::LintLevels) -> Self {
+        let mut engine = Self::new(None, false);
+        engine.lints = lints;
+        engine.quiet = true;
+        engine
+    }
+
+    pub fn reset(&mut self) {
+        self.errors = 0;
+        self.warnings = 0;
+        self.notes = 0;
+        self.diagnostics.clear();
+        self.seen.clear();
+        self.lints.scopes.clear();
+        self.io_failed = false;
+        self.aborted = false;
+    }
+
+    pub fn report(&mut self, mut diagnostic: diagnostic::Diagnostic) {
+        if self.aborted {
+            return;
+        }
+
+        if let Some(name) = diagnostic.lint {
+            diagnostic.severity = match self.lints.level(name, &diagnostic.span) {
+                lint::Level::Allow => return,
+                lint::Level::Warn => diagnostic::Severity::Warning,
+                lint::Level::Deny => diagnostic::Severity::Error
+            };
+        }
+
+        if self.warnings_as_errors && diagnostic.severity == diagnostic::Severity::Warning {
+            diagnostic.severity = diagnostic::Severity::Error;
+        }
+
+        if self.allow_warnings && diagnostic.severity == diagnostic::Severity::Warning {
+            return;
+        }
+
+        let key = (diagnostic.severity, diagnostic.kind.clone(), diagnostic.span.clone(), diagnostic.message.clone());
+
+        if !self.seen.insert(key) {
+            log::debug!("diagnostic", "dropped duplicate {:?} at {}:{}", diagnostic.kind, diagnostic.span.row, diagnostic.span.column);
+            return;
+        }
+
+        log::debug!("diagnostic", "report {} {:?} at {}[{}:{}]", diagnostic.severity, diagnostic.kind, diagnostic.span.stream, diagnostic.span.row, diagnostic.span.column);
+
+        if diagnostic.severity == diagnostic::Severity::Error && diagnostic.kind.is_io() {
+            self.io_failed = true;
+        }
+
+        match diagnostic.severity {
+            diagnostic::Severity::Error => self.errors += 1,
+            diagnostic::Severity::Warning => self.warnings += 1,
+            diagnostic::Severity::Note => self.notes += 1
+        }
+
+        if !self.quiet {
+            eprint!("{}", diagnostic);
+        }
+
+        self.diagnostics.push(diagnostic);
+
+        if self.error_limit.is_some_and(|limit| self.errors >= limit) {
+            self.aborted = true;
+
+            if !self.quiet {
+                eprintln!("{}: {}", diagnostic::Severity::Error, Message::new("engine-error-limit").with("count", self.errors));
+            }
+        }
+    }
+
+    pub fn aborted(&self) -> bool {
+        self.aborted
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors > 0
+    }
+
+    pub fn summary(&self) -> Option<String> {
+        let warnings = match self.warnings {
+            0 => None,
+            1 => Some(Message::new("summary-warning-one")),
+            count => Some(Message::new("summary-warning-other").with("count", count))
+        };
+
+        let errors = match self.errors {
+            0 => None,
+            1 => Some(Message::new("summary-error-one")),
+            count => Some(Message::new("summary-error-other").with("count", count))
+        };
+
+        match (errors, warnings) {
+            (None, None) => None,
+            (None, Some(warnings)) => Some(format!("{}: {}", diagnostic::Severity::Warning, warnings)),
+            (Some(errors), None) => Some(format!("{}: {}", diagnostic::Severity::Error, errors)),
+            (Some(errors), Some(warnings)) => Some(format!(
+                "{}: {}",
+                diagnostic::Severity::Error,
+                Message::new("summary-errors-and-warnings").with("errors", errors).with("warnings", warnings)
+            ))
+        }
+    }
+
+    pub fn finish(&self) -> u8 {
+        if let Some(summary) = self.summary().filter(|_| !self.quiet) {
+            eprintln!("{}", summary);
+        }
+
+        self.exit_code()
+    }
+
+    pub fn exit_code(&self) -> u8 {
+        if !self.has_errors() {
+            exit::SUCCESS
+        } else if self.io_failed {
+            exit::IO_ERROR
+        } else {
+            exit::COMPILE_ERROR
+        }
+    }
+}
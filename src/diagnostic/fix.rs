@@ -0,0 +1,71 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::message::Message;
+use crate::diagnostic::suggestion;
+
+pub struct Fixed {
+    pub content: String,
+    pub applied: usize,
+    pub rejected: Vec<diagnostic::Diagnostic>
+}
+
+fn overlaps(a: &suggestion::Edit, b: &suggestion::Edit) -> bool {
+    let (a_start, a_end) = (a.span.index, a.span.index + a.span.length);
+    let (b_start, b_end) = (b.span.index, b.span.index + b.span.length);
+
+    if a_start == a_end && b_start == b_end {
+        return a_start == b_start;
+    }
+
+    a_start < b_end && b_start < a_end
+}
+
+fn fits(content: &str, edit: &suggestion::Edit) -> bool {
+    let end = edit.span.index + edit.span.length;
+    end <= content.len() && content.is_char_boundary(edit.span.index) && content.is_char_boundary(end)
+}
+
+pub fn apply(content: &str, diagnostics: &[diagnostic::Diagnostic]) -> Fixed {
+    let mut accepted: Vec<&suggestion::Edit> = Vec::new();
+    let mut applied = 0;
+    let mut rejected = Vec::new();
+
+    let machine_applicable = diagnostics
+        .iter()
+        .flat_map(|diagnostic| diagnostic.suggestions.iter())
+        .filter(|suggestion| suggestion.applicability == suggestion::Applicability::MachineApplicable);
+
+    for suggestion in machine_applicable {
+        let conflict = suggestion.edits.iter().enumerate().find(|(index, edit)| {
+            !fits(content, edit)
+                || accepted.iter().any(|other| overlaps(edit, other))
+                || suggestion.edits[..*index].iter().any(|other| overlaps(edit, other))
+        });
+
+        match conflict {
+            Some((_, edit)) => rejected.push(diagnostic::Diagnostic::new(
+                diagnostic::Kind::ConflictingSuggestion,
+                edit.span.clone(),
+                Message::new("fix-overlapping").with("suggestion", &suggestion.message)
+            )),
+
+            None => {
+                accepted.extend(suggestion.edits.iter());
+                applied += 1;
+            }
+        }
+    }
+
+    accepted.sort_by_key(|edit| (edit.span.index, edit.span.length));
+
+    let mut fixed = String::from(content);
+
+    for edit in accepted.iter().rev() {
+        fixed.replace_range(edit.span.index..edit.span.index + edit.span.length, &edit.replacement);
+    }
+
+    Fixed {
+        content: fixed,
+        applied,
+        rejected
+    }
+}
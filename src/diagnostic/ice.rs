@@ -0,0 +1,114 @@
+use crate::diagnostic::diagnostic::Severity;
+use crate::diagnostic::message::Message;
+use std::backtrace::Backtrace;
+use std::env::args;
+use std::fmt::Write;
+use std::fs::{copy, create_dir_all, write};
+use std::panic::{set_hook, PanicHookInfo};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+struct State {
+    phase: &'static str,
+    input: Option<String>,
+    arguments: Vec<String>,
+    bundle: Option<PathBuf>
+}
+
+static STATE: Mutex<State> = Mutex::new(State {
+    phase: "startup",
+    input: None,
+    arguments: Vec::new(),
+    bundle: None
+});
+
+fn update(change: impl FnOnce(&mut State)) {
+    let mut state = STATE.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    change(&mut state);
+}
+
+pub fn install() {
+    update(|state| state.arguments = args().collect());
+    set_hook(Box::new(report));
+}
+
+pub fn bundle(directory: Option<PathBuf>) {
+    update(|state| state.bundle = directory);
+}
+
+pub fn phase(phase: &'static str) {
+    update(|state| state.phase = phase);
+}
+
+pub fn input(path: &str) {
+    update(|state| state.input = Some(String::from(path)));
+}
+
+fn message(info: &PanicHookInfo<'_>) -> String {
+    if let Some(message) = info.payload().downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = info.payload().downcast_ref::<String>() {
+        message.clone()
+    } else {
+        Message::new("ice-unknown-payload").to_string()
+    }
+}
+
+fn write_bundle(directory: &Path, state: &State, report: &str) -> std::io::Result<PathBuf> {
+    let stamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0);
+    let bundle = directory.join(format!("soyc-ice-{}-{}", stamp, std::process::id()));
+
+    create_dir_all(&bundle)?;
+    write(bundle.join("report.txt"), report)?;
+
+    if let Some(input) = &state.input {
+        let name = Path::new(input).file_name().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("input.soy"));
+        let _ = copy(input, bundle.join(name));
+    }
+
+    Ok(bundle)
+}
+
+fn report(info: &PanicHookInfo<'_>) {
+    let crashed = Message::new("ice-crashed").with("message", message(info));
+
+    let Ok(state) = STATE.try_lock() else {
+        eprintln!("{}: {}", Severity::Error, crashed);
+        return;
+    };
+
+    let location = info.location().map(|location| format!("{}:{}:{}", location.file(), location.line(), location.column()));
+    let input = state.input.as_deref().unwrap_or("<none>");
+
+    let mut text = String::new();
+    let _ = writeln!(text, "{}: {}", Severity::Error, crashed);
+    let _ = writeln!(text, "    {}: {}", Severity::Note, Message::new("ice-phase").with("version", VERSION).with("phase", state.phase).with("input", input));
+
+    if let Some(location) = &location {
+        let _ = writeln!(text, "    {}: {}", Severity::Note, Message::new("ice-location").with("location", location));
+    }
+
+    let _ = writeln!(text, "    {}: {}", Severity::Note, Message::new("ice-report"));
+
+    eprint!("{}", text);
+
+    let Some(directory) = &state.bundle else {
+        eprintln!("    {}: {}", Severity::Note, Message::new("ice-bundle-hint"));
+        return;
+    };
+
+    let mut bundle = text.clone();
+    let _ = writeln!(bundle, "\nversion: {}", VERSION);
+    let _ = writeln!(bundle, "phase: {}", state.phase);
+    let _ = writeln!(bundle, "input: {}", input);
+    let _ = writeln!(bundle, "arguments: {}", state.arguments.join(" "));
+    let _ = writeln!(bundle, "\nbacktrace:\n{}", Backtrace::force_capture());
+
+    match write_bundle(directory, &state, &bundle) {
+        Ok(path) => eprintln!("    {}: {}", Severity::Note, Message::new("ice-bundle-written").with("path", path.display())),
+        Err(error) => eprintln!("    {}: {}", Severity::Note, Message::new("ice-bundle-failed").with("reason", error))
+    }
+}
@@ -0,0 +1,122 @@
+use crate::diagnostic::message::Message;
+use crate::utils::similarity;
+use crate::utils::span::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny
+}
+
+pub struct Lint {
+    pub name: &'static str,
+    pub default: Level,
+    pub description: &'static str
+}
+
+pub const TRAILING_DOT_FLOAT: Lint = Lint {
+    name: "trailing_dot_float",
+    default: Level::Warn,
+    description: "float literals ending in '.'"
+};
+
+pub const LEADING_DOT_FLOAT: Lint = Lint {
+    name: "leading_dot_float",
+    default: Level::Warn,
+    description: "float literals starting with '.'"
+};
+
+pub const UNKNOWN_LINTS: Lint = Lint {
+    name: "unknown_lints",
+    default: Level::Warn,
+    description: "lint attributes naming lints that do not exist"
+};
+
+pub const MISSPELLED_KEYWORDS: Lint = Lint {
+    name: "misspelled_keywords",
+    default: Level::Warn,
+    description: "identifiers one edit away from a keyword, where that keyword is expected"
+};
+
+pub const MISSPELLED_NAMES: Lint = Lint {
+    name: "misspelled_names",
+    default: Level::Warn,
+    description: "names that are not in scope but are close to a name that is"
+};
+
+pub const LINTS: &[&Lint] = &[
+    &TRAILING_DOT_FLOAT,
+    &LEADING_DOT_FLOAT,
+    &UNKNOWN_LINTS,
+    &MISSPELLED_KEYWORDS,
+    &MISSPELLED_NAMES
+];
+
+pub fn find(name: &str) -> Option<&'static Lint> {
+    LINTS.iter().find(|lint| lint.name == name).copied()
+}
+
+pub fn did_you_mean(name: &str) -> Option<Message> {
+    similarity::did_you_mean(name, LINTS.iter().map(|lint| lint.name).chain(["warnings"]))
+}
+
+impl Level {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(Level::Allow),
+            "warn" => Some(Level::Warn),
+            "deny" => Some(Level::Deny),
+            _ => None
+        }
+    }
+
+    pub fn did_you_mean(name: &str) -> Option<Message> {
+        similarity::did_you_mean(name, ["allow", "warn", "deny"])
+    }
+}
+
+#[derive(Clone)]
+pub struct Scope {
+    pub stream: String,
+    pub start: usize,
+    pub end: usize,
+    pub lint: &'static str,
+    pub level: Level
+}
+
+#[derive(Clone, Default)]
+pub struct LintLevels {
+    pub command_line: Vec<(&'static str, Level)>,
+    pub scopes: Vec<Scope>
+}
+
+impl LintLevels {
+    pub fn set(&mut self, lint: &'static Lint, level: Level) {
+        self.command_line.retain(|(name, _)| *name != lint.name);
+        self.command_line.push((lint.name, level));
+    }
+
+    pub fn scope(&mut self, scope: Scope) {
+        self.scopes.push(scope);
+    }
+
+    pub fn level(&self, lint: &'static str, span: &Span) -> Level {
+        let scoped = self.scopes
+            .iter()
+            .filter(|scope| scope.lint == lint && scope.stream == span.stream)
+            .filter(|scope| scope.start <= span.index && span.index < scope.end)
+            .min_by_key(|scope| scope.end - scope.start);
+
+        if let Some(scope) = scoped {
+            return scope.level;
+        }
+
+        self.command_line
+            .iter()
+            .find(|(name, _)| *name == lint)
+            .map(|(_, level)| *level)
+            .or_else(|| find(lint).map(|lint| lint.default))
+            .unwrap_or(Level::Warn)
+    }
+}
@@ -1 +1,9 @@
-pub mod diagnostic;
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub mod diagnostic;
+pub mod message;
+pub mod catalog;
+pub mod suggestion;
+pub mod fix;
+pub mod engine;
+pub mod lint;
+pub mod ice;
@@ -0,0 +1,89 @@
+use crate::diagnostic::message::Message;
+use crate::utils::span::Span;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Applicability {
+    MachineApplicable,
+    MaybeIncorrect,
+    HasPlaceholders,
+    Unspecified
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edit {
+    pub span: Span,
+    pub replacement: String
+}
+
+impl Edit {
+    pub fn new(span: Span, replacement: String) -> Self {
+        Self {
+            span,
+            replacement
+        }
+    }
+
+    pub fn insert(mut span: Span, text: String) -> Self {
+        span.length = 0;
+        Self::new(span, text)
+    }
+
+    pub fn remove(span: Span) -> Self {
+        Self::new(span, String::new())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub message: Message,
+    pub edits: Vec<Edit>,
+    pub applicability: Applicability
+}
+
+impl Suggestion {
+    pub fn new(message: Message, edits: Vec<Edit>, applicability: Applicability) -> Self {
+        Self {
+            message,
+            edits,
+            applicability
+        }
+    }
+}
+
+impl Display for Applicability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Applicability::MachineApplicable => write!(f, "{}", Message::new("applicability-machine-applicable")),
+            Applicability::MaybeIncorrect => write!(f, "{}", Message::new("applicability-maybe-incorrect")),
+            Applicability::HasPlaceholders => write!(f, "{}", Message::new("applicability-has-placeholders")),
+            Applicability::Unspecified => write!(f, "{}", Message::new("applicability-unspecified"))
+        }
+    }
+}
+
+impl Display for Edit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}:{}] ", self.span.row, self.span.column)?;
+
+        let text = format!("{:?}", self.replacement);
+
+        match (self.span.length, self.replacement.is_empty()) {
+            (0, _) => write!(f, "{}", Message::new("edit-insert").with("text", text)),
+            (length, true) => write!(f, "{}", Message::new("edit-remove").with("length", length)),
+            (length, false) => write!(f, "{}", Message::new("edit-replace").with("length", length).with("text", text))
+        }
+    }
+}
+
+impl Display for Suggestion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "    {}: {} ({})", Message::new("label-help"), self.message, self.applicability)?;
+
+        for edit in &self.edits {
+            writeln!(f, "        {}", edit)?;
+        }
+
+        Ok(())
+    }
+}
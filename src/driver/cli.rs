@@ -0,0 +1,259 @@
+use crate::diagnostic::ice::VERSION;
+use std::fmt::Write;
+
+pub struct Flag {
+    pub names: &'static [&'static str],
+    pub value: Option<&'static str>,
+    pub help: &'static str
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Arity {
+    One,
+    Many
+}
+
+pub struct Command {
+    pub name: &'static str,
+    pub summary: &'static str,
+    pub arity: Arity,
+    pub package: bool,
+    pub flags: &'static [&'static [Flag]]
+}
+
+pub const HELP: Flag = Flag {
+    names: &["--help", "-h"],
+    value: None,
+    help: "print help for this command"
+};
+
+pub const COMMON: &[Flag] = &[
+    Flag {
+        names: &["--error-limit"],
+        value: Some("n"),
+        help: "stop after n errors, 0 means no limit"
+    },
+    Flag {
+        names: &["-A"],
+        value: Some("lint"),
+        help: "allow a lint, '-A warnings' silences all warnings"
+    },
+    Flag {
+        names: &["-W"],
+        value: Some("lint"),
+        help: "warn about a lint"
+    },
+    Flag {
+        names: &["-D"],
+        value: Some("lint"),
+        help: "deny a lint, '-D warnings' turns all warnings into errors"
+    },
+    Flag {
+        names: &["--crash-bundle"],
+        value: Some("directory"),
+        help: "write a reproduction bundle here if the compiler crashes"
+    },
+    Flag {
+        names: &["--max-file-size"],
+        value: Some("bytes"),
+        help: "refuse to read larger source files, 64 MiB by default"
+    },
+    Flag {
+        names: &["-Z"],
+        value: Some("option"),
+        help: "unstable options: time-passes, time-passes=json"
+    },
+    Flag {
+        names: &["--lang"],
+        value: Some("language"),
+        help: "language of diagnostic messages, read from <language>.catalog"
+    },
+    HELP
+];
+
+pub const PROFILE: &[Flag] = &[
+    Flag {
+        names: &["--profile"],
+        value: Some("name"),
+        help: "build profile from soy.toml, 'dev' by default"
+    },
+    Flag {
+        names: &["--release"],
+        value: None,
+        help: "use the release profile"
+    }
+];
+
+pub const BUILD: &[Flag] = &[
+    Flag {
+        names: &["--emit"],
+        value: Some("kinds"),
+        help: "artifacts to write: tokens, ast, cst, source, ir, asm, bytecode, obj"
+    },
+    Flag {
+        names: &["-o"],
+        value: Some("path"),
+        help: "write the only artifact here, '-' for standard output"
+    },
+    Flag {
+        names: &["--out-dir"],
+        value: Some("directory"),
+        help: "write artifacts into this directory"
+    }
+];
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "check",
+        summary: "check a file for errors without producing output",
+        arity: Arity::One,
+        package: true,
+        flags: &[PROFILE]
+    },
+    Command {
+        name: "build",
+        summary: "compile a file",
+        arity: Arity::One,
+        package: true,
+        flags: &[PROFILE, BUILD]
+    },
+    Command {
+        name: "run",
+        summary: "compile and run a file",
+        arity: Arity::One,
+        package: true,
+        flags: &[PROFILE]
+    },
+    Command {
+        name: "lex",
+        summary: "print the tokens of a file",
+        arity: Arity::One,
+        package: false,
+        flags: &[&[
+            Flag {
+                names: &["--format"],
+                value: Some("text|json"),
+                help: "output format of the token dump"
+            }
+        ]]
+    },
+    Command {
+        name: "parse",
+        summary: "print the syntax tree of a file",
+        arity: Arity::One,
+        package: false,
+        flags: &[]
+    },
+    Command {
+        name: "fmt",
+        summary: "format files in place",
+        arity: Arity::Many,
+        package: false,
+        flags: &[]
+    },
+    Command {
+        name: "watch",
+        summary: "check a file or package again whenever it changes",
+        arity: Arity::One,
+        package: true,
+        flags: &[&[
+            Flag {
+                names: &["--interval"],
+                value: Some("milliseconds"),
+                help: "how often to look for changes, 500 by default"
+            },
+            Flag {
+                names: &["--no-clear"],
+                value: None,
+                help: "do not clear the screen before each run"
+            }
+        ]]
+    },
+    Command {
+        name: "fix",
+        summary: "apply machine-applicable suggestions to files in place",
+        arity: Arity::Many,
+        package: false,
+        flags: &[]
+    }
+];
+
+pub fn find(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|command| command.name == name)
+}
+
+impl Command {
+    pub fn flags(&self) -> impl Iterator<Item = &'static Flag> {
+        self.flags.iter().copied().flatten().chain(COMMON.iter())
+    }
+
+    pub fn flag(&self, name: &str) -> Option<&'static Flag> {
+        self.flags().find(|flag| flag.names.contains(&name))
+    }
+
+    pub fn usage(&self) -> String {
+        match self.arity {
+            Arity::One if self.package => format!("usage: soyc {} [options] [<file>]", self.name),
+            Arity::One => format!("usage: soyc {} [options] <file>", self.name),
+            Arity::Many => format!("usage: soyc {} [options] <file>...", self.name)
+        }
+    }
+
+    pub fn help(&self) -> String {
+        let mut text = format!("soyc {} - {}\n\n{}\n\noptions:\n", self.name, self.summary, self.usage());
+
+        for flag in self.flags() {
+            let mut names = flag.names.join(", ");
+
+            if let Some(value) = flag.value {
+                let _ = write!(names, " <{}>", value);
+            }
+
+            let _ = writeln!(text, "    {:<28}{}", names, flag.help);
+        }
+
+        text
+    }
+}
+
+pub fn usage() -> String {
+    String::from("usage: soyc <command> [options] <file>...")
+}
+
+pub fn version() -> String {
+    format!("soyc {}", VERSION)
+}
+
+pub fn help() -> String {
+    let mut text = format!("{}\n\n{}\n\ncommands:\n", version(), usage());
+
+    for command in COMMANDS {
+        let _ = writeln!(text, "    {:<10}{}", command.name, command.summary);
+    }
+
+    let _ = writeln!(text, "\noptions:");
+
+    for (names, help) in [("-h, --help", "print this help"), ("-V, --version", "print the compiler version")] {
+        let _ = writeln!(text, "    {:<28}{}", names, help);
+    }
+    let _ = writeln!(text, "\nrun 'soyc <command> --help' for the options of a command");
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::driver::cli::COMMANDS;
+    use std::collections::HashSet;
+
+    #[test]
+    fn commands_list_each_flag_once() {
+        for command in COMMANDS {
+            let mut seen = HashSet::new();
+
+            for name in command.flags().flat_map(|flag| flag.names.iter()) {
+                assert!(seen.insert(name), "soyc {} lists {} twice", command.name, name);
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::ice;
+use crate::diagnostic::message::Message;
+use crate::driver::emit;
+use crate::driver::fix;
+use crate::driver::manifest::Manifest;
+use crate::driver::options::{Format, Options};
+use crate::driver::pipeline;
+use crate::driver::timing;
+use crate::driver::watch;
+use crate::syntax;
+use crate::syntax::ast::ItemKind;
+use crate::text::dump;
+use crate::utils::similarity;
+use crate::utils::span::Span;
+use std::env::current_dir;
+use std::io::stdout;
+
+fn cli(message: Message) -> diagnostic::Diagnostic {
+    diagnostic::Diagnostic::new(
+        diagnostic::Kind::Unsupported,
+        Span::new(String::from("cli"), 1, 1, 0, 0),
+        message
+    )
+}
+
+fn unsupported(engine: &mut DiagnosticEngine, command: &str, reason: &str) {
+    engine.report(cli(Message::new("driver-unsupported").with("command", command).with("reason", reason)));
+}
+
+fn lex(engine: &mut DiagnosticEngine, path: &String, format: Format) {
+    let Some(content) = pipeline::load(engine, path) else {
+        return;
+    };
+
+    let tokens = pipeline::lex(engine, path, &content);
+    let (ast, _) = pipeline::parse(&mut DiagnosticEngine::quiet(engine.lints.clone()), path, &tokens);
+    pipeline::lint(engine, path, &tokens, &ast);
+
+    ice::phase("printing tokens");
+
+    let mut output = stdout().lock();
+
+    let _ = match format {
+        Format::Text => dump::text(&tokens, &mut output),
+        Format::Json => dump::json(&tokens, &mut output)
+    };
+}
+
+fn parse(engine: &mut DiagnosticEngine, path: &String) {
+    let Some(unit) = pipeline::run(engine, path) else {
+        return;
+    };
+
+    ice::phase("printing syntax tree");
+
+    let _ = syntax::dump::text(&unit.ast, &mut stdout().lock());
+}
+
+fn package(engine: &mut DiagnosticEngine, options: &Options) -> Option<Vec<String>> {
+    let directory = current_dir().unwrap_or_default();
+
+    let Some(path) = Manifest::discover(&directory) else {
+        let mut diagnostic = cli(Message::new("manifest-not-found").with("directory", directory.display()));
+        diagnostic.kind = diagnostic::Kind::ManifestError;
+        engine.report(diagnostic);
+        return None;
+    };
+
+    let stream = path.to_string_lossy().into_owned();
+    ice::input(&stream);
+
+    let manifest = timing::pass("manifest", &stream, || Manifest::load(engine, &path))?;
+    let profile = options.profile.as_deref().unwrap_or("dev");
+
+    if manifest.profile(profile).is_none() {
+        let names = manifest.profiles.iter().map(|profile| profile.name.as_str());
+        let mut diagnostic = cli(Message::new("manifest-unknown-profile").with("profile", profile));
+        diagnostic.kind = diagnostic::Kind::ManifestError;
+
+        engine.report(diagnostic.with_optional_help(similarity::did_you_mean(profile, names)));
+        return None;
+    }
+
+    eprintln!(
+        "{}",
+        Message::new("package-compiling").with("name", &manifest.name).with("version", &manifest.version).with("profile", profile)
+    );
+
+    Some(manifest.files())
+}
+
+fn entry(engine: &mut DiagnosticEngine, units: &[pipeline::Unit]) {
+    let Some(unit) = units.first() else {
+        return;
+    };
+
+    let Some(main) = unit.ast.main() else {
+        let mut diagnostic = cli(Message::new("driver-no-main").with("path", &unit.path));
+        diagnostic.kind = diagnostic::Kind::SyntaxError;
+
+        engine.report(diagnostic.with_help(Message::new("driver-add-main")));
+        return;
+    };
+
+    let node = &unit.ast[main];
+
+    let ItemKind::Function(function) = &node.kind else {
+        return;
+    };
+
+    if !function.parameters.is_empty() {
+        engine.report(diagnostic::Diagnostic::new(
+            diagnostic::Kind::SyntaxError,
+            node.span.clone(),
+            Message::new("driver-main-parameters")
+        ));
+    }
+}
+
+pub fn compile(engine: &mut DiagnosticEngine, options: &Options) -> Vec<pipeline::Unit> {
+    let files = if options.files.is_empty() {
+        match package(engine, options) {
+            Some(files) => files,
+            None => return Vec::new()
+        }
+    } else {
+        options.files.clone()
+    };
+
+    let mut units = Vec::new();
+
+    for file in &files {
+        if engine.aborted() {
+            break;
+        }
+
+        units.extend(pipeline::run(engine, file));
+    }
+
+    units
+}
+
+pub fn run(options: &Options, engine: &mut DiagnosticEngine) {
+    let command = options.command.name;
+    let files = &options.files;
+
+    match command {
+        "check" => {
+            compile(engine, options);
+        },
+
+        "build" => {
+            let units = compile(engine, options);
+
+            if !engine.has_errors() && emit::artifacts(options).iter().any(emit::Artifact::is_code) {
+                entry(engine, &units);
+            }
+
+            emit::run(engine, options, &units);
+        },
+
+        "run" => {
+            let units = compile(engine, options);
+
+            if !engine.has_errors() {
+                entry(engine, &units);
+            }
+
+            if !engine.has_errors() {
+                unsupported(engine, command, "there is no code generator");
+            }
+        },
+
+        "lex" => lex(engine, &files[0], options.format),
+
+        "parse" => parse(engine, &files[0]),
+
+        "fmt" => unsupported(engine, command, "there is no formatter"),
+
+        "fix" => fix::run(engine, files),
+
+        "watch" => watch::run(engine, options),
+
+        _ => unsupported(engine, command, "unknown command")
+    }
+}
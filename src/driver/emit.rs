@@ -0,0 +1,194 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::message::Message;
+use crate::driver::options::Options;
+use crate::driver::pipeline::Unit;
+use crate::driver::timing;
+use crate::syntax;
+use crate::text::dump;
+use crate::utils::io::{create_directory, write_file};
+use crate::utils::span::Span;
+use std::collections::HashSet;
+use std::io::{stdout, Write};
+use std::path::{absolute, Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Artifact {
+    Tokens,
+    Ast,
+    Cst,
+    Source,
+    Ir,
+    Asm,
+    Bytecode,
+    Object
+}
+
+pub const ARTIFACTS: &[(&str, Artifact)] = &[
+    ("tokens", Artifact::Tokens),
+    ("ast", Artifact::Ast),
+    ("cst", Artifact::Cst),
+    ("source", Artifact::Source),
+    ("ir", Artifact::Ir),
+    ("asm", Artifact::Asm),
+    ("bytecode", Artifact::Bytecode),
+    ("obj", Artifact::Object)
+];
+
+impl Artifact {
+    pub fn from_name(name: &str) -> Option<Self> {
+        ARTIFACTS.iter().find(|(artifact, _)| *artifact == name).map(|(_, artifact)| *artifact)
+    }
+
+    pub fn name(&self) -> &'static str {
+        ARTIFACTS.iter().find(|(_, artifact)| artifact == self).map(|(name, _)| *name).unwrap_or_default()
+    }
+
+    pub fn is_code(&self) -> bool {
+        !matches!(self, Artifact::Tokens | Artifact::Ast | Artifact::Cst | Artifact::Source)
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Artifact::Tokens => "tokens",
+            Artifact::Ast => "ast",
+            Artifact::Cst => "cst",
+            Artifact::Source => "printed.soy",
+            Artifact::Ir => "ir",
+            Artifact::Asm => "s",
+            Artifact::Bytecode => "soyb",
+            Artifact::Object => "o"
+        }
+    }
+}
+
+fn cli(kind: diagnostic::Kind, message: Message) -> diagnostic::Diagnostic {
+    diagnostic::Diagnostic::new(kind, Span::new(String::from("cli"), 1, 1, 0, 0), message)
+}
+
+fn render(artifact: Artifact, unit: &Unit) -> Result<Vec<u8>, &'static str> {
+    let mut output = Vec::new();
+
+    match artifact {
+        Artifact::Tokens => {
+            let _ = dump::text(&unit.tokens, &mut output);
+        },
+
+        Artifact::Ast => {
+            let _ = syntax::dump::text(&unit.ast, &mut output);
+        },
+
+        Artifact::Cst => {
+            let root = syntax::cst::build(&unit.ast, &unit.path, &unit.content);
+            let _ = syntax::dump::cst(&root, 0, &mut output);
+        },
+
+        Artifact::Source => {
+            output.extend_from_slice(syntax::printer::source(&unit.ast).as_bytes());
+        },
+
+        Artifact::Ir | Artifact::Asm | Artifact::Bytecode | Artifact::Object => return Err("there is no code generator")
+    }
+
+    Ok(output)
+}
+
+fn parent(path: &str) -> PathBuf {
+    let path = absolute(path).unwrap_or_else(|_| PathBuf::from(path));
+    path.parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+fn common_directory(units: &[Unit]) -> PathBuf {
+    let mut parents = units.iter().map(|unit| parent(&unit.path));
+    let mut common = parents.next().unwrap_or_default();
+
+    for parent in parents {
+        while !parent.starts_with(&common) {
+            if !common.pop() {
+                break;
+            }
+        }
+    }
+
+    common
+}
+
+fn destination(options: &Options, artifact: Artifact, unit: &Unit, base: &Path) -> PathBuf {
+    if let Some(output) = &options.output {
+        return output.clone();
+    }
+
+    let input = Path::new(&unit.path);
+    let stem = input.file_stem().map(PathBuf::from).unwrap_or_else(|| PathBuf::from("out"));
+
+    let path = match &options.out_dir {
+        Some(directory) => {
+            let parent = parent(&unit.path);
+            let relative = parent.strip_prefix(base).unwrap_or(Path::new(""));
+            directory.join(relative).join(stem)
+        },
+
+        None => input.parent().map(Path::to_path_buf).unwrap_or_default().join(stem)
+    };
+
+    path.with_extension(artifact.extension())
+}
+
+pub fn artifacts(options: &Options) -> Vec<Artifact> {
+    if options.emit.is_empty() { vec![Artifact::Object] } else { options.emit.clone() }
+}
+
+pub fn run(engine: &mut DiagnosticEngine, options: &Options, units: &[Unit]) {
+    if options.output.is_some() && units.len() > 1 {
+        engine.report(cli(diagnostic::Kind::InvalidArgument, Message::new("emit-output-multiple-inputs")));
+        return;
+    }
+
+    let failed = engine.has_errors();
+    let artifacts = artifacts(options).into_iter().filter(|artifact| !failed || !artifact.is_code());
+    let base = common_directory(units);
+    let mut written = HashSet::new();
+
+    for artifact in artifacts {
+        for unit in units {
+            let rendered = timing::pass("emitting", &unit.path, || render(artifact, unit));
+
+            let content = match rendered {
+                Ok(content) => content,
+                Err(reason) => {
+                    engine.report(cli(
+                        diagnostic::Kind::Unsupported,
+                        Message::new("emit-unsupported").with("artifact", artifact.name()).with("reason", reason)
+                    ));
+                    break;
+                }
+            };
+
+            let path = destination(options, artifact, unit, &base);
+
+            if path == Path::new("-") {
+                let _ = stdout().lock().write_all(&content);
+                continue;
+            }
+
+            if !written.insert(path.clone()) {
+                engine.report(cli(
+                    diagnostic::Kind::InvalidArgument,
+                    Message::new("emit-duplicate-output").with("artifact", artifact.name()).with("path", path.display())
+                ));
+                continue;
+            }
+
+            if let Some(parent) = path.parent().filter(|_| options.out_dir.is_some()) {
+                if let Err(diagnostic) = create_directory(parent) {
+                    engine.report(diagnostic);
+                    continue;
+                }
+            }
+
+            if let Err(diagnostic) = write_file(&path.to_string_lossy().into_owned(), &String::from_utf8_lossy(&content)) {
+                engine.report(diagnostic);
+            }
+        }
+    }
+}
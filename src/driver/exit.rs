@@ -0,0 +1,10 @@
+use std::process::ExitCode;
+
+pub const SUCCESS: u8 = 0;
+pub const COMPILE_ERROR: u8 = 1;
+pub const USAGE_ERROR: u8 = 2;
+pub const IO_ERROR: u8 = 3;
+
+pub fn code(code: u8) -> ExitCode {
+    ExitCode::from(code)
+}
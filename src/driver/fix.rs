@@ -0,0 +1,38 @@
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::fix;
+use crate::diagnostic::message::Message;
+use crate::driver::pipeline;
+use crate::driver::timing;
+use crate::utils::io::write_file;
+
+pub fn run(engine: &mut DiagnosticEngine, paths: &[String]) {
+    for path in paths {
+        if engine.aborted() {
+            break;
+        }
+
+        let Some(content) = pipeline::load(engine, path) else {
+            continue;
+        };
+
+        let mut collector = DiagnosticEngine::quiet(engine.lints.clone());
+        pipeline::analyze(&mut collector, path, content.clone());
+
+        let fixed = timing::pass("fixing", path, || fix::apply(&content, &collector.diagnostics));
+
+        for diagnostic in fixed.rejected {
+            engine.report(diagnostic);
+        }
+
+        if fixed.applied > 0 {
+            if let Err(diagnostic) = write_file(path, &fixed.content) {
+                engine.report(diagnostic);
+                continue;
+            }
+
+            eprintln!("{}", Message::new("fix-applied").with("path", path).with("count", fixed.applied));
+        }
+
+        pipeline::analyze(engine, path, fixed.content);
+    }
+}
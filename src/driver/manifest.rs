@@ -0,0 +1,274 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::message::Message;
+use crate::utils::io::read_file;
+use crate::utils::similarity;
+use crate::utils::span::Span;
+use crate::utils::toml;
+use std::env::current_dir;
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+
+pub const FILE: &str = "soy.toml";
+pub const EDITIONS: &[&str] = &["2025"];
+
+pub struct Profile {
+    pub name: String,
+    pub opt_level: i64,
+    pub debug: bool
+}
+
+pub struct Manifest {
+    pub path: PathBuf,
+    pub root: PathBuf,
+    pub name: String,
+    pub version: String,
+    pub edition: String,
+    pub entry: PathBuf,
+    pub sources: Vec<PathBuf>,
+    pub profiles: Vec<Profile>
+}
+
+struct Reader<'a> {
+    engine: &'a mut DiagnosticEngine,
+    stream: String,
+    failed: bool
+}
+
+impl Reader<'_> {
+    fn error(&mut self, span: Span, message: Message) {
+        self.failed = true;
+        self.engine.report(diagnostic::Diagnostic::new(diagnostic::Kind::ManifestError, span, message));
+    }
+
+    fn unknown_keys(&mut self, table: &toml::Table, section: &str, known: &[&str]) {
+        for (key, item) in table {
+            if known.contains(&key.as_str()) {
+                continue;
+            }
+
+            self.engine.report(diagnostic::Diagnostic::new(
+                diagnostic::Kind::ManifestError,
+                item.span.clone(),
+                Message::new("manifest-unknown-key").with("key", format!("{}{}", section, key))
+            ).with_severity(diagnostic::Severity::Warning).with_optional_help(similarity::did_you_mean(key, known.iter().copied())));
+        }
+    }
+
+    fn string(&mut self, table: &toml::Table, key: &str, default: Option<&str>) -> Option<String> {
+        match table.get(key) {
+            Some(toml::Item { value: toml::Value::String(value), .. }) => Some(value.clone()),
+            Some(item) => {
+                self.error(item.span.clone(), Message::new("manifest-wrong-type").with("key", key).with("expected", "string"));
+                None
+            },
+            None => {
+                if default.is_none() {
+                    let span = Span::new(self.stream.clone(), 1, 1, 0, 0);
+                    self.error(span, Message::new("manifest-missing-key").with("key", key));
+                }
+
+                default.map(String::from)
+            }
+        }
+    }
+
+    fn strings(&mut self, table: &toml::Table, key: &str, default: &[&str]) -> Vec<String> {
+        let Some(item) = table.get(key) else {
+            return default.iter().map(|value| String::from(*value)).collect();
+        };
+
+        let toml::Value::Array(items) = &item.value else {
+            self.error(item.span.clone(), Message::new("manifest-wrong-type").with("key", key).with("expected", "array of strings"));
+            return Vec::new();
+        };
+
+        let mut strings = Vec::new();
+
+        for item in items {
+            match &item.value {
+                toml::Value::String(value) => strings.push(value.clone()),
+                _ => self.error(item.span.clone(), Message::new("manifest-wrong-type").with("key", key).with("expected", "array of strings"))
+            }
+        }
+
+        strings
+    }
+
+    fn profile(&mut self, name: &str, table: &toml::Table) -> Profile {
+        self.unknown_keys(table, &format!("profile.{}.", name), &["opt-level", "debug"]);
+
+        let opt_level = match table.get("opt-level") {
+            Some(toml::Item { value: toml::Value::Integer(level @ 0..=3), .. }) => *level,
+            Some(item) => {
+                self.error(item.span.clone(), Message::new("manifest-wrong-type").with("key", "opt-level").with("expected", "integer from 0 to 3"));
+                0
+            },
+            None => if name == "release" { 3 } else { 0 }
+        };
+
+        let debug = match table.get("debug") {
+            Some(toml::Item { value: toml::Value::Boolean(debug), .. }) => *debug,
+            Some(item) => {
+                self.error(item.span.clone(), Message::new("manifest-wrong-type").with("key", "debug").with("expected", "boolean"));
+                false
+            },
+            None => name != "release"
+        };
+
+        Profile {
+            name: String::from(name),
+            opt_level,
+            debug
+        }
+    }
+}
+
+fn display(path: PathBuf) -> PathBuf {
+    match current_dir() {
+        Ok(directory) => path.strip_prefix(&directory).map(Path::to_path_buf).unwrap_or(path),
+        Err(_) => path
+    }
+}
+
+fn collect(directory: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = read_dir(directory) else {
+        return;
+    };
+
+    let mut paths = entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            collect(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "soy") {
+            files.push(path);
+        }
+    }
+}
+
+impl Manifest {
+    pub fn discover(start: &Path) -> Option<PathBuf> {
+        start.ancestors().map(|directory| directory.join(FILE)).find(|path| path.is_file())
+    }
+
+    pub fn load(engine: &mut DiagnosticEngine, path: &Path) -> Option<Self> {
+        let stream = display(path.to_path_buf()).to_string_lossy().into_owned();
+
+        let content = match read_file(&path.to_string_lossy().into_owned()) {
+            Ok(content) => content,
+            Err(diagnostic) => {
+                engine.report(diagnostic);
+                return None;
+            }
+        };
+
+        let document = match toml::parse(&stream, &content) {
+            Ok(document) => document,
+            Err(diagnostic) => {
+                engine.report(diagnostic);
+                return None;
+            }
+        };
+
+        let mut reader = Reader {
+            engine,
+            stream: stream.clone(),
+            failed: false
+        };
+
+        reader.unknown_keys(&document, "", &["package", "profile"]);
+
+        let empty = toml::Table::new();
+
+        let package = match document.get("package") {
+            Some(toml::Item { value: toml::Value::Table(package), .. }) => package,
+            _ => {
+                reader.error(Span::new(stream, 1, 1, 0, 0), Message::new("manifest-missing-key").with("key", "package"));
+                return None;
+            }
+        };
+
+        reader.unknown_keys(package, "package.", &["name", "version", "edition", "entry", "sources"]);
+
+        let name = reader.string(package, "name", None).unwrap_or_default();
+        let version = reader.string(package, "version", None).unwrap_or_default();
+        let edition = reader.string(package, "edition", Some(EDITIONS[EDITIONS.len() - 1])).unwrap_or_default();
+        let entry = reader.string(package, "entry", Some("src/main.soy")).unwrap_or_default();
+        let sources = reader.strings(package, "sources", &["src"]);
+
+        if let Some(item) = package.get("edition").filter(|_| !EDITIONS.contains(&edition.as_str())) {
+            let help = similarity::did_you_mean(&edition, EDITIONS.iter().copied());
+            reader.failed = true;
+
+            reader.engine.report(diagnostic::Diagnostic::new(
+                diagnostic::Kind::ManifestError,
+                item.span.clone(),
+                Message::new("manifest-unknown-edition").with("edition", &edition)
+            ).with_optional_help(help));
+        }
+
+        let profiles = match document.get("profile").map(|item| &item.value) {
+            Some(toml::Value::Table(profiles)) => profiles,
+            Some(_) => {
+                let span = document["profile"].span.clone();
+                reader.error(span, Message::new("manifest-wrong-type").with("key", "profile").with("expected", "table"));
+                &empty
+            },
+            None => &empty
+        };
+
+        let mut loaded = vec![reader.profile("dev", &empty), reader.profile("release", &empty)];
+
+        for (name, item) in profiles {
+            let toml::Value::Table(table) = &item.value else {
+                reader.error(item.span.clone(), Message::new("manifest-wrong-type").with("key", format!("profile.{}", name)).with("expected", "table"));
+                continue;
+            };
+
+            let profile = reader.profile(name, table);
+            loaded.retain(|existing| existing.name != profile.name);
+            loaded.push(profile);
+        }
+
+        if reader.failed {
+            return None;
+        }
+
+        let root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        Some(Self {
+            path: path.to_path_buf(),
+            entry: root.join(entry),
+            sources: sources.iter().map(|source| root.join(source)).collect(),
+            root,
+            name,
+            version,
+            edition,
+            profiles: loaded
+        })
+    }
+
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    pub fn files(&self) -> Vec<String> {
+        let mut files = vec![self.entry.clone()];
+
+        for source in &self.sources {
+            collect(source, &mut files);
+        }
+
+        let mut unique = Vec::new();
+
+        for file in files.into_iter().map(display) {
+            if !unique.contains(&file) {
+                unique.push(file);
+            }
+        }
+
+        unique.into_iter().map(|file| file.to_string_lossy().into_owned()).collect()
+    }
+}
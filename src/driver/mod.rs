@@ -0,0 +1,10 @@
+pub mod exit;
+pub mod cli;
+pub mod options;
+pub mod manifest;
+pub mod pipeline;
+pub mod timing;
+pub mod emit;
+pub mod commands;
+pub mod fix;
+pub mod watch;
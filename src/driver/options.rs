@@ -0,0 +1,243 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::lint;
+use crate::diagnostic::message::Message;
+use crate::driver::cli;
+use crate::driver::emit;
+use crate::utils::similarity;
+use crate::utils::span::Span;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json
+}
+
+pub const UNSTABLE: &[&str] = &["time-passes", "time-passes=json"];
+
+pub struct Options {
+    pub command: &'static cli::Command,
+    pub help: bool,
+    pub error_limit: Option<usize>,
+    pub warnings_as_errors: bool,
+    pub allow_warnings: bool,
+    pub lints: lint::LintLevels,
+    pub crash_bundle: Option<PathBuf>,
+    pub language: Option<String>,
+    pub format: Format,
+    pub profile: Option<String>,
+    pub time_passes: Option<Format>,
+    pub max_file_size: Option<u64>,
+    pub emit: Vec<emit::Artifact>,
+    pub output: Option<PathBuf>,
+    pub out_dir: Option<PathBuf>,
+    pub interval: u64,
+    pub clear: bool,
+    pub files: Vec<String>
+}
+
+pub fn invalid(message: Message) -> diagnostic::Diagnostic {
+    diagnostic::Diagnostic::new(
+        diagnostic::Kind::InvalidArgument,
+        Span::new(String::from("cli"), 1, 1, 0, 0),
+        message
+    )
+}
+
+fn value<'a>(flag: &str, inline: Option<&'a str>, rest: &mut impl Iterator<Item = &'a String>) -> Result<&'a str, diagnostic::Diagnostic> {
+    match inline {
+        Some(value) => Ok(value),
+        None => rest.next().map(|value| value.as_str()).ok_or_else(|| invalid(Message::new("cli-missing-value").with("flag", flag)))
+    }
+}
+
+impl Options {
+    pub fn parse(command: &'static cli::Command, arguments: &[String]) -> Result<Self, diagnostic::Diagnostic> {
+        let mut options = Self {
+            command,
+            help: false,
+            error_limit: None,
+            warnings_as_errors: false,
+            allow_warnings: false,
+            lints: lint::LintLevels::default(),
+            crash_bundle: None,
+            language: None,
+            format: Format::Text,
+            profile: None,
+            time_passes: None,
+            max_file_size: None,
+            emit: Vec::new(),
+            output: None,
+            out_dir: None,
+            interval: 500,
+            clear: true,
+            files: Vec::new()
+        };
+
+        let mut rest = arguments.iter();
+
+        while let Some(argument) = rest.next() {
+            if !argument.starts_with('-') || argument == "-" {
+                options.files.push(argument.clone());
+                continue;
+            }
+
+            let attached = argument.get(..2).filter(|short| !argument.starts_with("--") && argument.len() > 2 && command.flag(short).is_some_and(|flag| flag.value.is_some()));
+
+            let (name, inline) = match (argument.split_once('='), attached) {
+                (Some((name, inline)), _) if argument.starts_with("--") => (name, Some(inline)),
+                (_, Some(short)) => (short, Some(&argument[2..])),
+                _ => (argument.as_str(), None)
+            };
+
+            let Some(flag) = command.flag(name) else {
+                let names = command.flags().flat_map(|flag| flag.names.iter().copied());
+
+                return Err(invalid(Message::new("cli-unknown-flag").with("flag", argument))
+                    .with_optional_help(similarity::did_you_mean(name, names)));
+            };
+
+            let value = match flag.value {
+                Some(_) => value(name, inline, &mut rest)?,
+                None if inline.is_some() => return Err(invalid(Message::new("cli-unexpected-value").with("flag", name))),
+                None => ""
+            };
+
+            options.apply(flag.names[0], value)?;
+        }
+
+        if options.output.is_some() && options.emit.len() > 1 {
+            return Err(invalid(Message::new("cli-output-multiple-artifacts")));
+        }
+
+        if !options.help {
+            match (command.arity, options.files.len()) {
+                (_, 0) if !command.package => return Err(invalid(Message::new("cli-missing-file").with("command", command.name))),
+                (cli::Arity::One, count) if count > 1 => return Err(invalid(Message::new("cli-too-many-files").with("command", command.name))),
+                _ => ()
+            }
+        }
+
+        Ok(options)
+    }
+
+    fn apply(&mut self, flag: &str, value: &str) -> Result<(), diagnostic::Diagnostic> {
+        let invalid_value = || invalid(Message::new("cli-invalid-value").with("flag", flag).with("value", value));
+
+        match flag {
+            "--help" => self.help = true,
+
+            "--error-limit" => self.error_limit = match value.parse::<usize>() {
+                Ok(0) => None,
+                Ok(limit) => Some(limit),
+                Err(_) => return Err(invalid_value())
+            },
+
+            "--crash-bundle" => self.crash_bundle = Some(PathBuf::from(value)),
+
+            "--lang" => self.language = Some(String::from(value)),
+
+            "--interval" => self.interval = match value.parse::<u64>() {
+                Ok(interval) if interval > 0 => interval,
+                _ => return Err(invalid_value())
+            },
+
+            "--no-clear" => self.clear = false,
+
+            "-Z" => match value {
+                "time-passes" | "time-passes=text" => self.time_passes = Some(Format::Text),
+                "time-passes=json" => self.time_passes = Some(Format::Json),
+                _ => return Err(invalid(Message::new("cli-unknown-unstable").with("option", value))
+                    .with_optional_help(similarity::did_you_mean(value, UNSTABLE.iter().copied())))
+            },
+
+            "--emit" => for name in value.split(',').map(str::trim) {
+                let Some(artifact) = emit::Artifact::from_name(name) else {
+                    let names = emit::ARTIFACTS.iter().map(|(name, _)| *name);
+
+                    return Err(invalid(Message::new("cli-unknown-artifact").with("artifact", name))
+                        .with_optional_help(similarity::did_you_mean(name, names)));
+                };
+
+                if !self.emit.contains(&artifact) {
+                    self.emit.push(artifact);
+                }
+            },
+
+            "--max-file-size" => self.max_file_size = match value.parse::<u64>() {
+                Ok(size) => Some(size),
+                Err(_) => return Err(invalid_value())
+            },
+
+            "-o" => self.output = Some(PathBuf::from(value)),
+
+            "--out-dir" => self.out_dir = Some(PathBuf::from(value)),
+
+            "--profile" => self.profile = Some(String::from(value)),
+
+            "--release" => self.profile = Some(String::from("release")),
+
+            "--format" => self.format = match value {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                _ => return Err(invalid_value())
+            },
+
+            "-A" | "-W" | "-D" => {
+                let level = match flag {
+                    "-A" => lint::Level::Allow,
+                    "-W" => lint::Level::Warn,
+                    _ => lint::Level::Deny
+                };
+
+                match (value, level) {
+                    ("warnings", level) => {
+                        self.warnings_as_errors = level == lint::Level::Deny;
+                        self.allow_warnings = level == lint::Level::Allow;
+                    },
+                    (name, level) => match lint::find(name) {
+                        Some(found) => self.lints.set(found, level),
+                        None => return Err(invalid(Message::new("cli-unknown-lint").with("name", name)).with_optional_help(lint::did_you_mean(name)))
+                    }
+                }
+            },
+
+            _ => return Err(invalid_value())
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::lint;
+    use crate::driver::cli;
+    use crate::driver::options::Options;
+
+    fn parse(arguments: &[&str]) -> Options {
+        let arguments = arguments.iter().map(|argument| String::from(*argument)).collect::<Vec<_>>();
+        Options::parse(cli::find("check").unwrap(), &arguments).expect("the arguments are valid")
+    }
+
+    #[test]
+    fn warnings_can_be_allowed_or_denied() {
+        let allowed = parse(&["-A", "warnings", "main.soy"]);
+        assert!(allowed.allow_warnings && !allowed.warnings_as_errors);
+
+        let denied = parse(&["-Awarnings", "-Dwarnings", "main.soy"]);
+        assert!(!denied.allow_warnings && denied.warnings_as_errors);
+
+        let reset = parse(&["-Dwarnings", "-Wwarnings", "main.soy"]);
+        assert!(!reset.allow_warnings && !reset.warnings_as_errors);
+    }
+
+    #[test]
+    fn short_flags_take_attached_values() {
+        let options = parse(&["-Atrailing_dot_float", "-Ztime-passes", "main.soy"]);
+
+        assert_eq!(options.lints.command_line, [(lint::TRAILING_DOT_FLOAT.name, lint::Level::Allow)]);
+        assert!(options.time_passes.is_some());
+        assert_eq!(options.files, ["main.soy"]);
+    }
+}
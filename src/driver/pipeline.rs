@@ -0,0 +1,82 @@
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::ice;
+use crate::driver::timing;
+use crate::syntax::ast::Ast;
+use crate::syntax::count;
+use crate::syntax::names;
+use crate::syntax::parser::Parser;
+use crate::text::lexer::Lexer;
+use crate::text::lints;
+use crate::text::token;
+use crate::utils::io::read_file;
+
+pub struct Unit {
+    pub path: String,
+    pub content: String,
+    pub tokens: Vec<token::Token>,
+    pub ast: Ast,
+    pub parsed: bool
+}
+
+pub fn load(engine: &mut DiagnosticEngine, path: &String) -> Option<String> {
+    ice::input(path);
+
+    match timing::pass("reading", path, || read_file(path)) {
+        Ok(content) => Some(content),
+        Err(diagnostic) => {
+            engine.report(diagnostic);
+            None
+        }
+    }
+}
+
+pub fn lex(engine: &mut DiagnosticEngine, path: &str, content: &str) -> Vec<token::Token> {
+    let tokens = timing::pass("lexing", path, || Lexer::new(String::from(path), String::from(content)).tokenize(engine));
+    timing::count("tokens", tokens.len());
+
+    tokens
+}
+
+pub fn parse(engine: &mut DiagnosticEngine, path: &str, tokens: &[token::Token]) -> (Ast, bool) {
+    let mut parser = Parser::new(tokens);
+    let parsed = timing::pass("parsing", path, || parser.parse(engine));
+    timing::count("nodes", count::nodes(&parser.ast).total());
+
+    (parser.ast, parsed)
+}
+
+pub fn lint(engine: &mut DiagnosticEngine, path: &str, tokens: &[token::Token], ast: &Ast) {
+    timing::pass("linting", path, || {
+        lints::check(tokens, ast, engine);
+        names::check(ast, engine);
+    });
+}
+
+pub fn run(engine: &mut DiagnosticEngine, path: &String) -> Option<Unit> {
+    let content = load(engine, path)?;
+
+    if engine.aborted() {
+        return None;
+    }
+
+    analyze(engine, path, content)
+}
+
+pub fn analyze(engine: &mut DiagnosticEngine, path: &str, content: String) -> Option<Unit> {
+    let tokens = lex(engine, path, &content);
+
+    if engine.aborted() {
+        return None;
+    }
+
+    let (ast, parsed) = parse(engine, path, &tokens);
+    lint(engine, path, &tokens, &ast);
+
+    Some(Unit {
+        path: String::from(path),
+        content,
+        tokens,
+        ast,
+        parsed
+    })
+}
@@ -0,0 +1,132 @@
+use crate::diagnostic::ice;
+use crate::driver::options::Format;
+use crate::utils::alloc;
+use crate::utils::json;
+use crate::utils::log;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub struct Pass {
+    pub name: &'static str,
+    pub file: String,
+    pub duration: Duration,
+    pub allocated: usize,
+    pub counts: Vec<(&'static str, usize)>
+}
+
+struct Timings {
+    format: Format,
+    passes: Vec<Pass>
+}
+
+static TIMINGS: Mutex<Option<Timings>> = Mutex::new(None);
+
+fn with<T>(change: impl FnOnce(&mut Option<Timings>) -> T) -> T {
+    let mut timings = TIMINGS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    change(&mut timings)
+}
+
+pub fn enable(format: Format) {
+    with(|timings| *timings = Some(Timings {
+        format,
+        passes: Vec::new()
+    }));
+}
+
+pub fn pass<T>(name: &'static str, file: &str, run: impl FnOnce() -> T) -> T {
+    ice::phase(name);
+    log::info!("driver", "start {} {}", name, file);
+
+    let allocated = alloc::allocated();
+    let start = Instant::now();
+    let result = run();
+    let duration = start.elapsed();
+    let allocated = alloc::allocated() - allocated;
+
+    log::info!("driver", "finish {} {} in {:.3}ms", name, file, duration.as_secs_f64() * 1000.0);
+
+    with(|timings| {
+        if let Some(timings) = timings {
+            timings.passes.push(Pass {
+                name,
+                file: String::from(file),
+                duration,
+                allocated,
+                counts: Vec::new()
+            });
+        }
+    });
+
+    result
+}
+
+pub fn count(unit: &'static str, count: usize) {
+    with(|timings| {
+        if let Some(pass) = timings.as_mut().and_then(|timings| timings.passes.last_mut()) {
+            pass.counts.push((unit, count));
+        }
+    });
+}
+
+fn bytes(count: usize) -> String {
+    match count {
+        0..1024 => format!("{} B", count),
+        1024..1048576 => format!("{:.1} KiB", count as f64 / 1024.0),
+        _ => format!("{:.1} MiB", count as f64 / 1048576.0)
+    }
+}
+
+fn table(passes: &[Pass]) -> String {
+    let width = passes.iter().map(|pass| pass.file.len()).max().unwrap_or(0).max(4);
+    let mut text = format!("{:<12}{:<width$}  {:>12}{:>14}  items\n", "pass", "file", "time", "allocated", width = width);
+
+    for pass in passes {
+        let counts = pass.counts.iter().map(|(unit, count)| format!("{} {}", count, unit)).collect::<Vec<_>>().join(", ");
+        let time = format!("{:.3}ms", pass.duration.as_secs_f64() * 1000.0);
+
+        let line = format!("{:<12}{:<width$}  {:>12}{:>14}  {}", pass.name, pass.file, time, bytes(pass.allocated), counts, width = width);
+        let _ = writeln!(text, "{}", line.trim_end());
+    }
+
+    let total = passes.iter().map(|pass| pass.duration).sum::<Duration>();
+    let allocated = passes.iter().map(|pass| pass.allocated).sum::<usize>();
+    let time = format!("{:.3}ms", total.as_secs_f64() * 1000.0);
+
+    let _ = writeln!(text, "{:<12}{:<width$}  {:>12}{:>14}", "total", "", time, bytes(allocated), width = width);
+    text
+}
+
+fn json(passes: &[Pass]) -> String {
+    let mut text = String::from("[\n");
+
+    for (index, pass) in passes.iter().enumerate() {
+        let counts = pass.counts.iter().map(|(unit, count)| format!("{}: {}", json::string(unit), count)).collect::<Vec<_>>().join(", ");
+        let separator = if index + 1 < passes.len() { "," } else { "" };
+
+        let _ = writeln!(
+            text,
+            "  {{\"pass\": {}, \"file\": {}, \"nanoseconds\": {}, \"allocated\": {}, \"counts\": {{{}}}}}{}",
+            json::string(pass.name),
+            json::string(&pass.file),
+            pass.duration.as_nanos(),
+            pass.allocated,
+            counts,
+            separator
+        );
+    }
+
+    text.push_str("]\n");
+    text
+}
+
+pub fn report() {
+    let Some(timings) = with(|timings| timings.take()) else {
+        return;
+    };
+
+    match timings.format {
+        Format::Text => eprint!("{}", table(&timings.passes)),
+        Format::Json => eprint!("{}", json(&timings.passes))
+    }
+}
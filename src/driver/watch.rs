@@ -0,0 +1,92 @@
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::message::Message;
+use crate::driver::commands;
+use crate::driver::manifest::Manifest;
+use crate::driver::options::Options;
+use std::env::current_dir;
+use std::fs::{metadata, read_dir};
+use std::io::{stdout, Write};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime};
+
+type Snapshot = Vec<(PathBuf, Option<(SystemTime, u64)>)>;
+
+fn walk(path: &Path, paths: &mut Vec<PathBuf>) {
+    paths.push(path.to_path_buf());
+
+    let Ok(entries) = read_dir(path) else {
+        return;
+    };
+
+    let mut children = entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect::<Vec<_>>();
+    children.sort();
+
+    for child in children {
+        if child.is_dir() {
+            walk(&child, paths);
+        } else if child.extension().is_some_and(|extension| extension == "soy") {
+            paths.push(child);
+        }
+    }
+}
+
+fn watched(options: &Options, engine: &DiagnosticEngine) -> Vec<PathBuf> {
+    if !options.files.is_empty() {
+        return options.files.iter().map(PathBuf::from).collect();
+    }
+
+    let Some(path) = Manifest::discover(&current_dir().unwrap_or_default()) else {
+        return Vec::new();
+    };
+
+    let mut paths = vec![path.clone()];
+    let mut quiet = DiagnosticEngine::quiet(engine.lints.clone());
+
+    if let Some(manifest) = Manifest::load(&mut quiet, &path) {
+        paths.push(manifest.entry.clone());
+
+        for source in &manifest.sources {
+            walk(source, &mut paths);
+        }
+    }
+
+    paths
+}
+
+fn snapshot(paths: Vec<PathBuf>) -> Snapshot {
+    paths
+        .into_iter()
+        .map(|path| {
+            let stamp = metadata(&path).and_then(|metadata| Ok((metadata.modified()?, metadata.len()))).ok();
+            (path, stamp)
+        })
+        .collect()
+}
+
+pub fn run(engine: &mut DiagnosticEngine, options: &Options) {
+    let interval = Duration::from_millis(options.interval);
+
+    loop {
+        if options.clear {
+            print!("\x1b[2J\x1b[H");
+            let _ = stdout().flush();
+        }
+
+        let current = snapshot(watched(options, engine));
+
+        engine.reset();
+        commands::compile(engine, options);
+        engine.finish();
+
+        eprintln!("{}", Message::new("watch-waiting").with("count", current.len()));
+
+        loop {
+            sleep(interval);
+
+            if snapshot(watched(options, engine)) != current {
+                break;
+            }
+        }
+    }
+}
@@ -1,20 +1,117 @@
+#![allow(clippy::result_large_err)]
+
 pub mod utils;
 pub mod diagnostic;
 pub mod text;
+pub mod syntax;
+pub mod driver;
 
 use std::env::args;
+use std::process::ExitCode;
+use diagnostic::diagnostic::{Diagnostic, Kind, Severity};
+use diagnostic::engine::DiagnosticEngine;
+use diagnostic::message::Message;
+use driver::{cli, exit};
+use driver::options::{invalid, Options};
+use utils::similarity;
+use utils::span::Span;
 
-fn usage() -> i32 {
-    eprintln!("usage: soyc <file name>");
-    1
+fn usage(line: String, help: &str) -> ExitCode {
+    eprintln!("{}", line);
+    eprintln!("run '{} --help' for more information", help);
+    exit::code(exit::USAGE_ERROR)
 }
 
-fn main() -> Result<(), i32> {
+fn main() -> ExitCode {
+    diagnostic::ice::install();
+
     let arguments = args().collect::<Vec<_>>();
+    let mut engine = DiagnosticEngine::new(None, false);
+
+    for directive in utils::log::init("SOYC_LOG") {
+        engine.report(Diagnostic::new(
+            Kind::InvalidArgument,
+            Span::new(String::from("SOYC_LOG"), 1, 1, 0, 0),
+            Message::new("log-invalid-directive").with("directive", directive)
+        ).with_severity(Severity::Warning));
+    }
+
+    let Some(name) = arguments.get(1) else {
+        engine.report(invalid(Message::new("cli-missing-command")));
+        return usage(cli::usage(), "soyc");
+    };
+
+    match name.as_str() {
+        "-h" | "--help" => {
+            print!("{}", cli::help());
+            return exit::code(exit::SUCCESS);
+        },
+
+        "help" => {
+            match arguments.get(2).and_then(|name| cli::find(name)) {
+                Some(command) => print!("{}", command.help()),
+                None => print!("{}", cli::help())
+            }
+
+            return exit::code(exit::SUCCESS);
+        },
+
+        "-V" | "--version" => {
+            println!("{}", cli::version());
+            return exit::code(exit::SUCCESS);
+        },
+
+        _ => ()
+    }
+
+    let Some(command) = cli::find(name) else {
+        let names = cli::COMMANDS.iter().map(|command| command.name);
 
-    if arguments.len() < 2 {
-        return Err(usage());
+        engine.report(invalid(Message::new("cli-unknown-command").with("command", name))
+            .with_optional_help(similarity::did_you_mean(name, names)));
+
+        return usage(cli::usage(), "soyc");
+    };
+
+    let options = match Options::parse(command, &arguments[2..]) {
+        Ok(options) => options,
+        Err(diagnostic) => {
+            engine.report(diagnostic);
+            return usage(command.usage(), &format!("soyc {}", command.name));
+        }
     };
 
-    Ok(())
+    diagnostic::ice::bundle(options.crash_bundle.clone());
+
+    if options.help {
+        print!("{}", command.help());
+        return exit::code(exit::SUCCESS);
+    }
+
+    if let Some(language) = &options.language {
+        if let Err(diagnostic) = diagnostic::catalog::activate(language) {
+            engine.report(diagnostic);
+            return usage(command.usage(), &format!("soyc {}", command.name));
+        }
+    }
+
+    engine.error_limit = options.error_limit;
+    engine.warnings_as_errors = options.warnings_as_errors;
+    engine.allow_warnings = options.allow_warnings;
+    engine.lints = options.lints.clone();
+
+    if let Some(size) = options.max_file_size {
+        utils::io::set_limit(size);
+    }
+
+    if let Some(format) = options.time_passes {
+        driver::timing::enable(format);
+    }
+
+    utils::log::info!("driver", "running 'soyc {}' on {:?}", command.name, options.files);
+
+    driver::commands::run(&options, &mut engine);
+    driver::timing::report();
+
+    exit::code(engine.finish())
 }
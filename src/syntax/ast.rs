@@ -0,0 +1,471 @@
+use crate::text::token;
+use crate::utils::span::Span;
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExpressionId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StatementId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatternId(pub u32);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<K> {
+    pub id: NodeId,
+    pub kind: K,
+    pub span: Span
+}
+
+pub type Expression = Node<ExpressionKind>;
+pub type Statement = Node<StatementKind>;
+pub type Item = Node<ItemKind>;
+pub type Type = Node<TypeKind>;
+pub type Pattern = Node<PatternKind>;
+pub type Path = Vec<String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOperator {
+    Negate,
+    Not,
+    BitwiseNot
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    LeftShift,
+    RightShift,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpressionKind {
+    Integer(String),
+    Float(String),
+    Boolean(bool),
+    String(String),
+    Char(String),
+    Identifier(String),
+    Path(Path),
+    Tuple(Vec<ExpressionId>),
+    Unary(UnaryOperator, ExpressionId),
+    Binary(ExpressionId, BinaryOperator, ExpressionId),
+    Group(ExpressionId),
+    Call(ExpressionId, Vec<ExpressionId>),
+    Field(ExpressionId, String),
+    Struct(Path, Vec<FieldValue>),
+    Assign(ExpressionId, Option<BinaryOperator>, ExpressionId),
+    Block(Block),
+    If(ExpressionId, ExpressionId, Option<ExpressionId>),
+    While(ExpressionId, ExpressionId),
+    For(PatternId, ExpressionId, ExpressionId),
+    Break,
+    Continue,
+    Return(Option<ExpressionId>),
+    Match(ExpressionId, Vec<Arm>),
+    Error
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Arm {
+    pub pattern: PatternId,
+    pub guard: Option<ExpressionId>,
+    pub body: ExpressionId,
+    pub span: Span
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldValue {
+    pub name: String,
+    pub value: ExpressionId,
+    pub span: Span
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub statements: Vec<StatementId>,
+    pub tail: Option<ExpressionId>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatementKind {
+    Let(PatternId, Option<TypeId>, Option<ExpressionId>),
+    Expression(ExpressionId),
+    Item(ItemId)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    pub pattern: PatternId,
+    pub ty: TypeId,
+    pub span: Span
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub parameters: Vec<Parameter>,
+    pub output: Option<TypeId>,
+    pub body: ExpressionId
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub ty: TypeId,
+    pub span: Span
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Struct {
+    pub name: String,
+    pub fields: Vec<Field>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum VariantData {
+    Unit,
+    Tuple(Vec<TypeId>),
+    Struct(Vec<Field>)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    pub name: String,
+    pub data: VariantData,
+    pub span: Span
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enum {
+    pub name: String,
+    pub variants: Vec<Variant>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemKind {
+    Function(Function),
+    Struct(Struct),
+    Enum(Enum),
+    Error
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeKind {
+    Named(String)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldPattern {
+    pub name: String,
+    pub pattern: PatternId,
+    pub span: Span
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatternKind {
+    Wildcard,
+    Binding(String),
+    Literal(ExpressionId),
+    Tuple(Vec<PatternId>),
+    Path(Path),
+    TupleVariant(Path, Vec<PatternId>),
+    Struct(Path, Vec<FieldPattern>)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    pub name: String,
+    pub arguments: Vec<(String, Span)>,
+    pub span: Span,
+    pub end: usize
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Ast {
+    pub expressions: Vec<Expression>,
+    pub statements: Vec<Statement>,
+    pub items: Vec<Item>,
+    pub types: Vec<Type>,
+    pub patterns: Vec<Pattern>,
+    pub roots: Vec<ItemId>,
+    pub attributes: Vec<Attribute>,
+    next: u32
+}
+
+impl<K> Node<K> {
+    pub fn new(id: NodeId, kind: K, span: Span) -> Self {
+        Self {
+            id,
+            kind,
+            span
+        }
+    }
+}
+
+impl ExpressionKind {
+    pub fn is_block_like(&self) -> bool {
+        matches!(self, ExpressionKind::Block(_) | ExpressionKind::If(..) | ExpressionKind::While(..) | ExpressionKind::For(..) | ExpressionKind::Match(..))
+    }
+
+    pub fn is_place(&self) -> bool {
+        matches!(self, ExpressionKind::Identifier(_) | ExpressionKind::Field(..))
+    }
+}
+
+impl Ast {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn main(&self) -> Option<ItemId> {
+        self.roots.iter().copied().find(|id| matches!(&self[*id].kind, ItemKind::Function(function) if function.name == "main"))
+    }
+
+    pub fn len(&self) -> usize {
+        self.next as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.next == 0
+    }
+
+    fn id(&mut self) -> NodeId {
+        let id = NodeId(self.next);
+        self.next += 1;
+        id
+    }
+
+    pub fn expression(&mut self, kind: ExpressionKind, span: Span) -> ExpressionId {
+        let node = Node::new(self.id(), kind, span);
+        self.expressions.push(node);
+        ExpressionId(self.expressions.len() as u32 - 1)
+    }
+
+    pub fn statement(&mut self, kind: StatementKind, span: Span) -> StatementId {
+        let node = Node::new(self.id(), kind, span);
+        self.statements.push(node);
+        StatementId(self.statements.len() as u32 - 1)
+    }
+
+    pub fn item(&mut self, kind: ItemKind, span: Span) -> ItemId {
+        let node = Node::new(self.id(), kind, span);
+        self.items.push(node);
+        ItemId(self.items.len() as u32 - 1)
+    }
+
+    pub fn ty(&mut self, kind: TypeKind, span: Span) -> TypeId {
+        let node = Node::new(self.id(), kind, span);
+        self.types.push(node);
+        TypeId(self.types.len() as u32 - 1)
+    }
+
+    pub fn pattern(&mut self, kind: PatternKind, span: Span) -> PatternId {
+        let node = Node::new(self.id(), kind, span);
+        self.patterns.push(node);
+        PatternId(self.patterns.len() as u32 - 1)
+    }
+}
+
+impl Index<ExpressionId> for Ast {
+    type Output = Expression;
+
+    fn index(&self, id: ExpressionId) -> &Expression {
+        &self.expressions[id.0 as usize]
+    }
+}
+
+impl IndexMut<ExpressionId> for Ast {
+    fn index_mut(&mut self, id: ExpressionId) -> &mut Expression {
+        &mut self.expressions[id.0 as usize]
+    }
+}
+
+impl Index<StatementId> for Ast {
+    type Output = Statement;
+
+    fn index(&self, id: StatementId) -> &Statement {
+        &self.statements[id.0 as usize]
+    }
+}
+
+impl IndexMut<StatementId> for Ast {
+    fn index_mut(&mut self, id: StatementId) -> &mut Statement {
+        &mut self.statements[id.0 as usize]
+    }
+}
+
+impl Index<ItemId> for Ast {
+    type Output = Item;
+
+    fn index(&self, id: ItemId) -> &Item {
+        &self.items[id.0 as usize]
+    }
+}
+
+impl IndexMut<ItemId> for Ast {
+    fn index_mut(&mut self, id: ItemId) -> &mut Item {
+        &mut self.items[id.0 as usize]
+    }
+}
+
+impl Index<TypeId> for Ast {
+    type Output = Type;
+
+    fn index(&self, id: TypeId) -> &Type {
+        &self.types[id.0 as usize]
+    }
+}
+
+impl IndexMut<TypeId> for Ast {
+    fn index_mut(&mut self, id: TypeId) -> &mut Type {
+        &mut self.types[id.0 as usize]
+    }
+}
+
+impl Index<PatternId> for Ast {
+    type Output = Pattern;
+
+    fn index(&self, id: PatternId) -> &Pattern {
+        &self.patterns[id.0 as usize]
+    }
+}
+
+impl IndexMut<PatternId> for Ast {
+    fn index_mut(&mut self, id: PatternId) -> &mut Pattern {
+        &mut self.patterns[id.0 as usize]
+    }
+}
+
+impl UnaryOperator {
+    pub fn from_token(kind: &token::Kind) -> Option<Self> {
+        match kind {
+            token::Kind::Minus => Some(UnaryOperator::Negate),
+            token::Kind::Exclamation => Some(UnaryOperator::Not),
+            token::Kind::Tilde => Some(UnaryOperator::BitwiseNot),
+            _ => None
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            UnaryOperator::Negate => "-",
+            UnaryOperator::Not => "!",
+            UnaryOperator::BitwiseNot => "~"
+        }
+    }
+}
+
+impl BinaryOperator {
+    pub fn from_token(kind: &token::Kind) -> Option<Self> {
+        match kind {
+            token::Kind::PipePipe => Some(BinaryOperator::Or),
+            token::Kind::AmpersandAmpersand => Some(BinaryOperator::And),
+            token::Kind::AssignAssign => Some(BinaryOperator::Equal),
+            token::Kind::ExclamationAssign => Some(BinaryOperator::NotEqual),
+            token::Kind::LessThan => Some(BinaryOperator::Less),
+            token::Kind::LessThanOrEqual => Some(BinaryOperator::LessOrEqual),
+            token::Kind::GreaterThan => Some(BinaryOperator::Greater),
+            token::Kind::GreaterThanOrEqual => Some(BinaryOperator::GreaterOrEqual),
+            token::Kind::Pipe => Some(BinaryOperator::BitwiseOr),
+            token::Kind::Caret => Some(BinaryOperator::BitwiseXor),
+            token::Kind::Ampersand => Some(BinaryOperator::BitwiseAnd),
+            token::Kind::BitwiseLeftShift => Some(BinaryOperator::LeftShift),
+            token::Kind::BitwiseRightShift => Some(BinaryOperator::RightShift),
+            token::Kind::Plus => Some(BinaryOperator::Add),
+            token::Kind::Minus => Some(BinaryOperator::Subtract),
+            token::Kind::Asterisk => Some(BinaryOperator::Multiply),
+            token::Kind::Slash => Some(BinaryOperator::Divide),
+            token::Kind::Modulo => Some(BinaryOperator::Modulo),
+            _ => None
+        }
+    }
+
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Or => 1,
+            BinaryOperator::And => 2,
+
+            BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+            | BinaryOperator::Less
+            | BinaryOperator::LessOrEqual
+            | BinaryOperator::Greater
+            | BinaryOperator::GreaterOrEqual => 3,
+
+            BinaryOperator::BitwiseOr => 4,
+            BinaryOperator::BitwiseXor => 5,
+            BinaryOperator::BitwiseAnd => 6,
+            BinaryOperator::LeftShift | BinaryOperator::RightShift => 7,
+            BinaryOperator::Add | BinaryOperator::Subtract => 8,
+            BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Modulo => 9
+        }
+    }
+
+    pub fn from_assignment(kind: &token::Kind) -> Option<Option<Self>> {
+        match kind {
+            token::Kind::Assign => Some(None),
+            token::Kind::PlusAssign => Some(Some(BinaryOperator::Add)),
+            token::Kind::MinusAssign => Some(Some(BinaryOperator::Subtract)),
+            token::Kind::AsteriskAssign => Some(Some(BinaryOperator::Multiply)),
+            token::Kind::SlashAssign => Some(Some(BinaryOperator::Divide)),
+            token::Kind::ModuloAssign => Some(Some(BinaryOperator::Modulo)),
+            token::Kind::AmpersandAssign => Some(Some(BinaryOperator::BitwiseAnd)),
+            token::Kind::PipeAssign => Some(Some(BinaryOperator::BitwiseOr)),
+            token::Kind::CaretAssign => Some(Some(BinaryOperator::BitwiseXor)),
+            token::Kind::BitwiseLeftShiftAssign => Some(Some(BinaryOperator::LeftShift)),
+            token::Kind::BitwiseRightShiftAssign => Some(Some(BinaryOperator::RightShift)),
+            _ => None
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOperator::Or => "||",
+            BinaryOperator::And => "&&",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::Less => "<",
+            BinaryOperator::LessOrEqual => "<=",
+            BinaryOperator::Greater => ">",
+            BinaryOperator::GreaterOrEqual => ">=",
+            BinaryOperator::BitwiseOr => "|",
+            BinaryOperator::BitwiseXor => "^",
+            BinaryOperator::BitwiseAnd => "&",
+            BinaryOperator::LeftShift => "<<",
+            BinaryOperator::RightShift => ">>",
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Modulo => "%"
+        }
+    }
+}
+
+pub const PREFIX_PRECEDENCE: u8 = 10;
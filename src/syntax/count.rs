@@ -0,0 +1,49 @@
+use crate::syntax::ast::{Ast, ExpressionId, ItemId, PatternId, StatementId, TypeId};
+use crate::syntax::visit::{self, Visitor};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counter {
+    pub items: usize,
+    pub statements: usize,
+    pub expressions: usize,
+    pub patterns: usize,
+    pub types: usize
+}
+
+impl Counter {
+    pub fn total(&self) -> usize {
+        self.items + self.statements + self.expressions + self.patterns + self.types
+    }
+}
+
+impl Visitor<'_> for Counter {
+    fn visit_item(&mut self, ast: &Ast, id: ItemId) {
+        self.items += 1;
+        visit::walk_item(self, ast, id)
+    }
+
+    fn visit_statement(&mut self, ast: &Ast, id: StatementId) {
+        self.statements += 1;
+        visit::walk_statement(self, ast, id)
+    }
+
+    fn visit_expression(&mut self, ast: &Ast, id: ExpressionId) {
+        self.expressions += 1;
+        visit::walk_expression(self, ast, id)
+    }
+
+    fn visit_pattern(&mut self, ast: &Ast, id: PatternId) {
+        self.patterns += 1;
+        visit::walk_pattern(self, ast, id)
+    }
+
+    fn visit_ty(&mut self, _ast: &Ast, _id: TypeId) {
+        self.types += 1;
+    }
+}
+
+pub fn nodes(ast: &Ast) -> Counter {
+    let mut counter = Counter::default();
+    visit::walk_ast(&mut counter, ast);
+    counter
+}
@@ -0,0 +1,572 @@
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::lint;
+use crate::syntax::ast::{Arm, Ast, ExpressionId, ExpressionKind, Field, FieldPattern, FieldValue, ItemId, ItemKind, Parameter, PatternId, PatternKind, StatementId, StatementKind, TypeId, Variant};
+use crate::syntax::visit::{self, Visitor};
+use crate::text::lexer::Lexer;
+use crate::text::token;
+use crate::utils::span::Span;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyntaxKind {
+    SourceFile,
+
+    Function,
+    Parameter,
+    Struct,
+    Enum,
+    Variant,
+    FieldDeclaration,
+    ItemError,
+
+    Let,
+    ExpressionStatement,
+
+    Literal,
+    Identifier,
+    Path,
+    Tuple,
+    Unary,
+    Binary,
+    Group,
+    Call,
+    FieldAccess,
+    StructLiteral,
+    FieldValue,
+    Assign,
+    Block,
+    If,
+    While,
+    For,
+    Break,
+    Continue,
+    Return,
+    Match,
+    Arm,
+    Error,
+
+    Type,
+
+    WildcardPattern,
+    BindingPattern,
+    LiteralPattern,
+    TuplePattern,
+    PathPattern,
+    TupleVariantPattern,
+    StructPattern,
+    FieldPattern
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct GreenToken {
+    pub kind: token::Kind,
+    pub text: String
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GreenElement {
+    Node(Arc<GreenNode>),
+    Token(Arc<GreenToken>)
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct GreenNode {
+    pub kind: SyntaxKind,
+    pub width: usize,
+    pub children: Vec<GreenElement>
+}
+
+impl GreenToken {
+    pub fn new(kind: token::Kind, text: String) -> Self {
+        Self {
+            kind,
+            text
+        }
+    }
+}
+
+impl GreenElement {
+    pub fn width(&self) -> usize {
+        match self {
+            GreenElement::Node(node) => node.width,
+            GreenElement::Token(token) => token.text.len()
+        }
+    }
+}
+
+impl GreenNode {
+    pub fn new(kind: SyntaxKind, children: Vec<GreenElement>) -> Self {
+        Self {
+            kind,
+            width: children.iter().map(GreenElement::width).sum(),
+            children
+        }
+    }
+
+    pub fn replace_child(&self, index: usize, element: GreenElement) -> Self {
+        let mut children = self.children.clone();
+        children[index] = element;
+
+        Self::new(self.kind, children)
+    }
+
+    pub fn write(&self, text: &mut String) {
+        for child in &self.children {
+            match child {
+                GreenElement::Node(node) => node.write(text),
+                GreenElement::Token(token) => text.push_str(&token.text)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxNode {
+    pub green: Arc<GreenNode>,
+    pub parent: Option<Arc<SyntaxNode>>,
+    pub index: usize,
+    pub offset: usize
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxToken {
+    pub green: Arc<GreenToken>,
+    pub parent: SyntaxNode,
+    pub index: usize,
+    pub offset: usize
+}
+
+#[derive(Debug, Clone)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(SyntaxToken)
+}
+
+impl SyntaxNode {
+    pub fn root(green: Arc<GreenNode>) -> Self {
+        Self {
+            green,
+            parent: None,
+            index: 0,
+            offset: 0
+        }
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        self.green.kind
+    }
+
+    pub fn range(&self) -> (usize, usize) {
+        (self.offset, self.offset + self.green.width)
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = String::with_capacity(self.green.width);
+        self.green.write(&mut text);
+        text
+    }
+
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        self.parent.as_deref().cloned()
+    }
+
+    pub fn children_with_tokens(&self) -> Vec<SyntaxElement> {
+        let parent = Arc::new(self.clone());
+        let mut offset = self.offset;
+        let mut elements = Vec::with_capacity(self.green.children.len());
+
+        for (index, child) in self.green.children.iter().enumerate() {
+            elements.push(match child {
+                GreenElement::Node(green) => SyntaxElement::Node(SyntaxNode {
+                    green: green.clone(),
+                    parent: Some(parent.clone()),
+                    index,
+                    offset
+                }),
+
+                GreenElement::Token(green) => SyntaxElement::Token(SyntaxToken {
+                    green: green.clone(),
+                    parent: self.clone(),
+                    index,
+                    offset
+                })
+            });
+
+            offset += child.width();
+        }
+
+        elements
+    }
+
+    pub fn children(&self) -> Vec<SyntaxNode> {
+        self.children_with_tokens().into_iter().filter_map(|element| match element {
+            SyntaxElement::Node(node) => Some(node),
+            SyntaxElement::Token(_) => None
+        }).collect()
+    }
+
+    pub fn tokens(&self) -> Vec<SyntaxToken> {
+        self.children_with_tokens().into_iter().filter_map(|element| match element {
+            SyntaxElement::Token(token) if !token.kind().is_trivia() => Some(token),
+            _ => None
+        }).collect()
+    }
+
+    pub fn descendants(&self) -> Vec<SyntaxNode> {
+        let mut nodes = vec![self.clone()];
+        let mut position = 0;
+
+        while position < nodes.len() {
+            let children = nodes[position].children();
+            nodes.extend(children);
+            position += 1;
+        }
+
+        nodes
+    }
+
+    pub fn replace_with(&self, green: GreenNode) -> Arc<GreenNode> {
+        let green = Arc::new(green);
+
+        match &self.parent {
+            Some(parent) => parent.replace_with(parent.green.replace_child(self.index, GreenElement::Node(green))),
+            None => green
+        }
+    }
+}
+
+impl SyntaxToken {
+    pub fn kind(&self) -> &token::Kind {
+        &self.green.kind
+    }
+
+    pub fn text(&self) -> &str {
+        &self.green.text
+    }
+
+    pub fn range(&self) -> (usize, usize) {
+        (self.offset, self.offset + self.green.text.len())
+    }
+
+    pub fn replace_with(&self, green: GreenToken) -> Arc<GreenNode> {
+        let element = GreenElement::Token(Arc::new(green));
+        self.parent.replace_with(self.parent.green.replace_child(self.index, element))
+    }
+}
+
+struct Range {
+    kind: SyntaxKind,
+    start: usize,
+    end: usize
+}
+
+#[derive(Default)]
+struct Collector {
+    ranges: Vec<Range>
+}
+
+impl Collector {
+    fn push(&mut self, kind: SyntaxKind, span: &Span) {
+        self.ranges.push(Range {
+            kind,
+            start: span.index,
+            end: span.index + span.length
+        });
+    }
+}
+
+impl<'a> Visitor<'a> for Collector {
+    fn visit_item(&mut self, ast: &'a Ast, id: ItemId) {
+        let node = &ast[id];
+
+        let kind = match &node.kind {
+            ItemKind::Function(_) => SyntaxKind::Function,
+            ItemKind::Struct(_) => SyntaxKind::Struct,
+            ItemKind::Enum(_) => SyntaxKind::Enum,
+            ItemKind::Error => SyntaxKind::ItemError
+        };
+
+        self.push(kind, &node.span);
+        visit::walk_item(self, ast, id)
+    }
+
+    fn visit_parameter(&mut self, ast: &'a Ast, parameter: &'a Parameter) {
+        self.push(SyntaxKind::Parameter, &parameter.span);
+        visit::walk_parameter(self, ast, parameter)
+    }
+
+    fn visit_field(&mut self, ast: &'a Ast, field: &'a Field) {
+        self.push(SyntaxKind::FieldDeclaration, &field.span);
+        visit::walk_field(self, ast, field)
+    }
+
+    fn visit_variant(&mut self, ast: &'a Ast, variant: &'a Variant) {
+        self.push(SyntaxKind::Variant, &variant.span);
+        visit::walk_variant(self, ast, variant)
+    }
+
+    fn visit_statement(&mut self, ast: &'a Ast, id: StatementId) {
+        let node = &ast[id];
+
+        match &node.kind {
+            StatementKind::Let(..) => self.push(SyntaxKind::Let, &node.span),
+            StatementKind::Expression(_) => self.push(SyntaxKind::ExpressionStatement, &node.span),
+            StatementKind::Item(_) => ()
+        }
+
+        visit::walk_statement(self, ast, id)
+    }
+
+    fn visit_expression(&mut self, ast: &'a Ast, id: ExpressionId) {
+        let node = &ast[id];
+
+        let kind = match &node.kind {
+            ExpressionKind::Integer(_)
+            | ExpressionKind::Float(_)
+            | ExpressionKind::Boolean(_)
+            | ExpressionKind::String(_)
+            | ExpressionKind::Char(_) => SyntaxKind::Literal,
+
+            ExpressionKind::Identifier(_) => SyntaxKind::Identifier,
+            ExpressionKind::Path(_) => SyntaxKind::Path,
+            ExpressionKind::Tuple(_) => SyntaxKind::Tuple,
+            ExpressionKind::Unary(..) => SyntaxKind::Unary,
+            ExpressionKind::Binary(..) => SyntaxKind::Binary,
+            ExpressionKind::Group(_) => SyntaxKind::Group,
+            ExpressionKind::Call(..) => SyntaxKind::Call,
+            ExpressionKind::Field(..) => SyntaxKind::FieldAccess,
+            ExpressionKind::Struct(..) => SyntaxKind::StructLiteral,
+            ExpressionKind::Assign(..) => SyntaxKind::Assign,
+            ExpressionKind::Block(_) => SyntaxKind::Block,
+            ExpressionKind::If(..) => SyntaxKind::If,
+            ExpressionKind::While(..) => SyntaxKind::While,
+            ExpressionKind::For(..) => SyntaxKind::For,
+            ExpressionKind::Break => SyntaxKind::Break,
+            ExpressionKind::Continue => SyntaxKind::Continue,
+            ExpressionKind::Return(_) => SyntaxKind::Return,
+            ExpressionKind::Match(..) => SyntaxKind::Match,
+            ExpressionKind::Error => SyntaxKind::Error
+        };
+
+        self.push(kind, &node.span);
+        visit::walk_expression(self, ast, id)
+    }
+
+    fn visit_field_value(&mut self, ast: &'a Ast, field: &'a FieldValue) {
+        self.push(SyntaxKind::FieldValue, &field.span);
+        visit::walk_field_value(self, ast, field)
+    }
+
+    fn visit_arm(&mut self, ast: &'a Ast, arm: &'a Arm) {
+        self.push(SyntaxKind::Arm, &arm.span);
+        visit::walk_arm(self, ast, arm)
+    }
+
+    fn visit_pattern(&mut self, ast: &'a Ast, id: PatternId) {
+        let node = &ast[id];
+
+        let kind = match &node.kind {
+            PatternKind::Wildcard => SyntaxKind::WildcardPattern,
+            PatternKind::Binding(_) => SyntaxKind::BindingPattern,
+            PatternKind::Literal(_) => SyntaxKind::LiteralPattern,
+            PatternKind::Tuple(_) => SyntaxKind::TuplePattern,
+            PatternKind::Path(_) => SyntaxKind::PathPattern,
+            PatternKind::TupleVariant(..) => SyntaxKind::TupleVariantPattern,
+            PatternKind::Struct(..) => SyntaxKind::StructPattern
+        };
+
+        self.push(kind, &node.span);
+        visit::walk_pattern(self, ast, id)
+    }
+
+    fn visit_field_pattern(&mut self, ast: &'a Ast, field: &'a FieldPattern) {
+        self.push(SyntaxKind::FieldPattern, &field.span);
+        visit::walk_field_pattern(self, ast, field)
+    }
+
+    fn visit_ty(&mut self, ast: &'a Ast, id: TypeId) {
+        self.push(SyntaxKind::Type, &ast[id].span);
+    }
+}
+
+struct Open {
+    kind: SyntaxKind,
+    end: usize,
+    children: Vec<GreenElement>
+}
+
+fn leaves(path: &str, content: &str) -> Vec<GreenElement> {
+    let mut engine = DiagnosticEngine::quiet(lint::LintLevels::default());
+    let tokens = Lexer::new(String::from(path), String::from(content)).with_trivia().tokenize(&mut engine);
+
+    let mut leaves = Vec::with_capacity(tokens.len());
+    let mut position = 0;
+
+    for token in tokens.iter().filter(|token| token.kind != token::Kind::EndOfFile) {
+        if token.span.index > position {
+            let gap = String::from_utf8_lossy(&content.as_bytes()[position..token.span.index]).into_owned();
+            leaves.push(GreenElement::Token(Arc::new(GreenToken::new(token::Kind::Unhandled, gap))));
+        }
+
+        let text = String::from_utf8_lossy(&content.as_bytes()[token.span.index..token.span.index + token.span.length]).into_owned();
+        leaves.push(GreenElement::Token(Arc::new(GreenToken::new(token.kind.clone(), text))));
+        position = token.span.index + token.span.length;
+    }
+
+    if position < content.len() {
+        let rest = String::from_utf8_lossy(&content.as_bytes()[position..]).into_owned();
+        leaves.push(GreenElement::Token(Arc::new(GreenToken::new(token::Kind::Unhandled, rest))));
+    }
+
+    leaves
+}
+
+fn close(stack: &mut Vec<Open>) {
+    let open = stack.pop().expect("the source file node is never closed early");
+    let node = GreenElement::Node(Arc::new(GreenNode::new(open.kind, open.children)));
+
+    stack.last_mut().expect("the source file node is never closed early").children.push(node);
+}
+
+pub fn build(ast: &Ast, path: &str, content: &str) -> SyntaxNode {
+    let mut collector = Collector::default();
+    visit::walk_ast(&mut collector, ast);
+
+    let mut ranges = collector.ranges;
+    ranges.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    let mut ranges = ranges.into_iter().peekable();
+
+    let mut stack = vec![Open {
+        kind: SyntaxKind::SourceFile,
+        end: usize::MAX,
+        children: Vec::new()
+    }];
+
+    let mut offset = 0;
+
+    for leaf in leaves(path, content) {
+        while stack.len() > 1 && stack[stack.len() - 1].end <= offset {
+            close(&mut stack);
+        }
+
+        while let Some(range) = ranges.next_if(|range| range.start <= offset) {
+            while stack.len() > 1 && stack[stack.len() - 1].end < range.end {
+                close(&mut stack);
+            }
+
+            stack.push(Open {
+                kind: range.kind,
+                end: range.end,
+                children: Vec::new()
+            });
+
+            if range.end <= offset {
+                close(&mut stack);
+            }
+        }
+
+        offset += leaf.width();
+        stack.last_mut().expect("the source file node is never closed early").children.push(leaf);
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+
+    let root = stack.pop().expect("the source file node is never closed early");
+
+    SyntaxNode::root(Arc::new(GreenNode::new(root.kind, root.children)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build, GreenElement, GreenNode, GreenToken, SyntaxElement, SyntaxKind, SyntaxNode};
+    use crate::diagnostic::engine::DiagnosticEngine;
+    use crate::diagnostic::lint;
+    use crate::syntax::parser::Parser;
+    use crate::text::lexer::Lexer;
+    use crate::text::token;
+    use std::fs;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    fn tree(content: &str) -> (SyntaxNode, bool) {
+        let mut engine = DiagnosticEngine::quiet(lint::LintLevels::default());
+        let tokens = Lexer::new(String::from("test"), String::from(content)).tokenize(&mut engine);
+        let mut parser = Parser::new(&tokens);
+        let parsed = parser.parse(&mut engine);
+
+        (build(&parser.ast, "test", content), parsed)
+    }
+
+    fn child(node: &SyntaxNode, index: usize) -> &Arc<GreenNode> {
+        match &node.green.children[index] {
+            GreenElement::Node(green) => green,
+            GreenElement::Token(_) => panic!("child {} of {:?} is a token", index, node.kind())
+        }
+    }
+
+    #[test]
+    fn examples_are_lossless() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.extension().is_some_and(|extension| extension == "soy") {
+                let content = fs::read_to_string(&path).unwrap();
+                let (root, parsed) = tree(&content);
+
+                assert!(parsed, "{}", path.display());
+                assert_eq!(root.text(), content, "{}", path.display());
+            }
+        }
+    }
+
+    #[test]
+    fn comments_are_kept() {
+        let content = "// entry\nfn main() { /* nothing */\n    let x = 1; // one\n    x\n}\n/* trailing */";
+        let (root, parsed) = tree(content);
+
+        assert!(parsed);
+        assert_eq!(root.text(), content);
+
+        let comments = root.descendants().iter().flat_map(|node| node.children_with_tokens()).filter(|element| {
+            matches!(element, SyntaxElement::Token(token) if *token.kind() == token::Kind::Comment)
+        }).count();
+
+        assert_eq!(comments, 4);
+    }
+
+    #[test]
+    fn parse_errors_are_lossless() {
+        let content = "fn main() {\n    let = ;\n    1 +\n}\n\nstruct { x int }\n@ fn f(a: int) { a }\n";
+        let (root, parsed) = tree(content);
+
+        assert!(!parsed);
+        assert_eq!(root.text(), content);
+        assert!(root.descendants().iter().any(|node| node.kind() == SyntaxKind::Function));
+    }
+
+    #[test]
+    fn replacing_shares_unchanged_subtrees() {
+        let (root, _) = tree("fn a() {}\n\nfn b() -> int {\n    1 + 2\n}\n");
+
+        let literal = root.descendants().into_iter().find(|node| node.kind() == SyntaxKind::Literal && node.text() == "1").unwrap();
+        let binary = literal.parent().unwrap();
+
+        let replacement = GreenNode::new(SyntaxKind::Literal, vec![GreenElement::Token(Arc::new(GreenToken::new(token::Kind::Integer, String::from("42"))))]);
+        let replaced = SyntaxNode::root(literal.replace_with(replacement));
+
+        assert_eq!(replaced.text(), "fn a() {}\n\nfn b() -> int {\n    42 + 2\n}\n");
+        assert!(Arc::ptr_eq(child(&root, 0), child(&replaced, 0)));
+        assert!(!Arc::ptr_eq(&root.green, &replaced.green));
+
+        let rebuilt = replaced.descendants().into_iter().find(|node| node.kind() == SyntaxKind::Binary).unwrap();
+        let last = binary.green.children.len() - 1;
+
+        assert!(Arc::ptr_eq(child(&binary, last), child(&rebuilt, last)));
+        assert!(!Arc::ptr_eq(child(&binary, 0), child(&rebuilt, 0)));
+    }
+}
@@ -0,0 +1,316 @@
+use crate::syntax::ast::{Ast, ExpressionId, ExpressionKind, Field, ItemId, ItemKind, PatternId, PatternKind, StatementId, StatementKind, TypeId, TypeKind, VariantData};
+use crate::syntax::cst::{SyntaxElement, SyntaxNode};
+use crate::utils::span::Span;
+use std::io::{Result, Write};
+
+fn line(output: &mut impl Write, span: &Span, depth: usize, text: &str) -> Result<()> {
+    let position = format!("{}:{}", span.row, span.column);
+    writeln!(output, "{:<10}{}{}", position, "  ".repeat(depth), text)
+}
+
+fn expression(ast: &Ast, id: ExpressionId, depth: usize, output: &mut impl Write) -> Result<()> {
+    let node = &ast[id];
+    let span = &node.span;
+
+    match &node.kind {
+        ExpressionKind::Integer(value) => line(output, span, depth, &format!("Integer {}", value)),
+        ExpressionKind::Float(value) => line(output, span, depth, &format!("Float {}", value)),
+        ExpressionKind::Boolean(value) => line(output, span, depth, &format!("Boolean {}", value)),
+        ExpressionKind::String(value) => line(output, span, depth, &format!("String {}", value)),
+        ExpressionKind::Char(value) => line(output, span, depth, &format!("Char {}", value)),
+        ExpressionKind::Identifier(name) => line(output, span, depth, &format!("Identifier {}", name)),
+        ExpressionKind::Path(path) => line(output, span, depth, &format!("Path {}", path.join("::"))),
+
+        ExpressionKind::Tuple(elements) => {
+            line(output, span, depth, "Tuple")?;
+
+            for element in elements {
+                expression(ast, *element, depth + 1, output)?;
+            }
+
+            Ok(())
+        },
+
+        ExpressionKind::Unary(operator, operand) => {
+            line(output, span, depth, &format!("Unary {}", operator.symbol()))?;
+            expression(ast, *operand, depth + 1, output)
+        },
+
+        ExpressionKind::Binary(left, operator, right) => {
+            line(output, span, depth, &format!("Binary {}", operator.symbol()))?;
+            expression(ast, *left, depth + 1, output)?;
+            expression(ast, *right, depth + 1, output)
+        },
+
+        ExpressionKind::Group(inner) => {
+            line(output, span, depth, "Group")?;
+            expression(ast, *inner, depth + 1, output)
+        },
+
+        ExpressionKind::Call(callee, arguments) => {
+            line(output, span, depth, "Call")?;
+            expression(ast, *callee, depth + 1, output)?;
+
+            for argument in arguments {
+                expression(ast, *argument, depth + 1, output)?;
+            }
+
+            Ok(())
+        },
+
+        ExpressionKind::Field(target, name) => {
+            line(output, span, depth, &format!("Field {}", name))?;
+            expression(ast, *target, depth + 1, output)
+        },
+
+        ExpressionKind::Struct(name, fields) => {
+            line(output, span, depth, &format!("Struct {}", name.join("::")))?;
+
+            for field in fields {
+                line(output, &field.span, depth + 1, &format!("Field {}", field.name))?;
+                expression(ast, field.value, depth + 2, output)?;
+            }
+
+            Ok(())
+        },
+
+        ExpressionKind::Assign(target, operator, value) => {
+            line(output, span, depth, &format!("Assign {}=", operator.map(|operator| operator.symbol()).unwrap_or_default()))?;
+            expression(ast, *target, depth + 1, output)?;
+            expression(ast, *value, depth + 1, output)
+        },
+
+        ExpressionKind::Block(block) => {
+            line(output, span, depth, "Block")?;
+
+            for id in &block.statements {
+                statement(ast, *id, depth + 1, output)?;
+            }
+
+            match block.tail {
+                Some(tail) => expression(ast, tail, depth + 1, output),
+                None => Ok(())
+            }
+        },
+
+        ExpressionKind::If(condition, then, otherwise) => {
+            line(output, span, depth, "If")?;
+            expression(ast, *condition, depth + 1, output)?;
+            expression(ast, *then, depth + 1, output)?;
+
+            match otherwise {
+                Some(otherwise) => expression(ast, *otherwise, depth + 1, output),
+                None => Ok(())
+            }
+        },
+
+        ExpressionKind::While(condition, body) => {
+            line(output, span, depth, "While")?;
+            expression(ast, *condition, depth + 1, output)?;
+            expression(ast, *body, depth + 1, output)
+        },
+
+        ExpressionKind::For(binding, iterable, body) => {
+            line(output, span, depth, "For")?;
+            pattern(ast, *binding, depth + 1, output)?;
+            expression(ast, *iterable, depth + 1, output)?;
+            expression(ast, *body, depth + 1, output)
+        },
+
+        ExpressionKind::Break => line(output, span, depth, "Break"),
+        ExpressionKind::Continue => line(output, span, depth, "Continue"),
+
+        ExpressionKind::Return(value) => {
+            line(output, span, depth, "Return")?;
+
+            match value {
+                Some(value) => expression(ast, *value, depth + 1, output),
+                None => Ok(())
+            }
+        },
+
+        ExpressionKind::Error => line(output, span, depth, "Error"),
+
+        ExpressionKind::Match(scrutinee, arms) => {
+            line(output, span, depth, "Match")?;
+            expression(ast, *scrutinee, depth + 1, output)?;
+
+            for arm in arms {
+                line(output, &arm.span, depth + 1, "Arm")?;
+                pattern(ast, arm.pattern, depth + 2, output)?;
+
+                if let Some(guard) = arm.guard {
+                    line(output, &ast[guard].span, depth + 2, "Guard")?;
+                    expression(ast, guard, depth + 3, output)?;
+                }
+
+                expression(ast, arm.body, depth + 2, output)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn statement(ast: &Ast, id: StatementId, depth: usize, output: &mut impl Write) -> Result<()> {
+    let node = &ast[id];
+
+    match &node.kind {
+        StatementKind::Let(binding, annotation, value) => {
+            line(output, &node.span, depth, "Let")?;
+            pattern(ast, *binding, depth + 1, output)?;
+
+            if let Some(annotation) = annotation {
+                ty(ast, *annotation, depth + 1, output)?;
+            }
+
+            match value {
+                Some(value) => expression(ast, *value, depth + 1, output),
+                None => Ok(())
+            }
+        },
+
+        StatementKind::Expression(value) => {
+            line(output, &node.span, depth, "Expression")?;
+            expression(ast, *value, depth + 1, output)
+        },
+
+        StatementKind::Item(id) => item(ast, *id, depth, output)
+    }
+}
+
+fn fields(ast: &Ast, list: &[Field], depth: usize, output: &mut impl Write) -> Result<()> {
+    for field in list {
+        line(output, &field.span, depth, &format!("Field {}", field.name))?;
+        ty(ast, field.ty, depth + 1, output)?;
+    }
+
+    Ok(())
+}
+
+fn item(ast: &Ast, id: ItemId, depth: usize, output: &mut impl Write) -> Result<()> {
+    let node = &ast[id];
+
+    match &node.kind {
+        ItemKind::Function(function) => {
+            line(output, &node.span, depth, &format!("Function {}", function.name))?;
+
+            for parameter in &function.parameters {
+                line(output, &parameter.span, depth + 1, "Parameter")?;
+                pattern(ast, parameter.pattern, depth + 2, output)?;
+                ty(ast, parameter.ty, depth + 2, output)?;
+            }
+
+            if let Some(output_type) = function.output {
+                ty(ast, output_type, depth + 1, output)?;
+            }
+
+            expression(ast, function.body, depth + 1, output)
+        },
+
+        ItemKind::Struct(item) => {
+            line(output, &node.span, depth, &format!("Struct {}", item.name))?;
+            fields(ast, &item.fields, depth + 1, output)
+        },
+
+        ItemKind::Error => line(output, &node.span, depth, "Error"),
+
+        ItemKind::Enum(item) => {
+            line(output, &node.span, depth, &format!("Enum {}", item.name))?;
+
+            for variant in &item.variants {
+                line(output, &variant.span, depth + 1, &format!("Variant {}", variant.name))?;
+
+                match &variant.data {
+                    VariantData::Unit => (),
+                    VariantData::Struct(list) => fields(ast, list, depth + 2, output)?,
+
+                    VariantData::Tuple(types) => {
+                        for id in types {
+                            ty(ast, *id, depth + 2, output)?;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn pattern(ast: &Ast, id: PatternId, depth: usize, output: &mut impl Write) -> Result<()> {
+    let node = &ast[id];
+
+    match &node.kind {
+        PatternKind::Wildcard => line(output, &node.span, depth, "Wildcard"),
+        PatternKind::Binding(name) => line(output, &node.span, depth, &format!("Binding {}", name)),
+
+        PatternKind::Literal(value) => {
+            line(output, &node.span, depth, "Literal")?;
+            expression(ast, *value, depth + 1, output)
+        },
+
+        PatternKind::Path(path) => line(output, &node.span, depth, &format!("Path {}", path.join("::"))),
+
+        PatternKind::Tuple(elements) => {
+            line(output, &node.span, depth, "Tuple")?;
+
+            for element in elements {
+                pattern(ast, *element, depth + 1, output)?;
+            }
+
+            Ok(())
+        },
+
+        PatternKind::TupleVariant(path, elements) => {
+            line(output, &node.span, depth, &format!("Variant {}", path.join("::")))?;
+
+            for element in elements {
+                pattern(ast, *element, depth + 1, output)?;
+            }
+
+            Ok(())
+        },
+
+        PatternKind::Struct(path, fields) => {
+            line(output, &node.span, depth, &format!("Struct {}", path.join("::")))?;
+
+            for field in fields {
+                line(output, &field.span, depth + 1, &format!("Field {}", field.name))?;
+                pattern(ast, field.pattern, depth + 2, output)?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+fn ty(ast: &Ast, id: TypeId, depth: usize, output: &mut impl Write) -> Result<()> {
+    let node = &ast[id];
+
+    match &node.kind {
+        TypeKind::Named(name) => line(output, &node.span, depth, &format!("Type {}", name))
+    }
+}
+
+pub fn text(ast: &Ast, output: &mut impl Write) -> Result<()> {
+    for root in &ast.roots {
+        item(ast, *root, 0, output)?;
+    }
+
+    Ok(())
+}
+
+pub fn cst(node: &SyntaxNode, depth: usize, output: &mut impl Write) -> Result<()> {
+    let (start, end) = node.range();
+    writeln!(output, "{}{:?} {}..{}", "  ".repeat(depth), node.kind(), start, end)?;
+
+    for element in node.children_with_tokens() {
+        match element {
+            SyntaxElement::Node(child) => cst(&child, depth + 1, output)?,
+            SyntaxElement::Token(token) => writeln!(output, "{}{:?} {:?}", "  ".repeat(depth + 1), token.kind(), token.text())?
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod ast;
+pub mod parser;
+pub mod cst;
+pub mod view;
+pub mod dump;
+pub mod printer;
+pub mod visit;
+pub mod count;
+pub mod names;
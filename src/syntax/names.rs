@@ -0,0 +1,150 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::lint;
+use crate::diagnostic::message::Message;
+use crate::syntax::ast::{Arm, Ast, ExpressionId, ExpressionKind, ItemId, ItemKind, PatternId, PatternKind, StatementId, StatementKind};
+use crate::syntax::visit::{self, Visitor};
+use crate::utils::similarity;
+
+struct Names<'a, 'e> {
+    scopes: Vec<Vec<&'a str>>,
+    engine: &'e mut DiagnosticEngine
+}
+
+fn item_name(ast: &Ast, id: ItemId) -> Option<&str> {
+    match &ast[id].kind {
+        ItemKind::Function(function) => Some(&function.name),
+        ItemKind::Struct(item) => Some(&item.name),
+        ItemKind::Enum(item) => Some(&item.name),
+        ItemKind::Error => None
+    }
+}
+
+impl<'a> Names<'a, '_> {
+    fn scoped(&mut self, names: Vec<&'a str>, visit: impl FnOnce(&mut Self)) {
+        self.scopes.push(names);
+        visit(self);
+        self.scopes.pop();
+    }
+
+    fn check(&mut self, ast: &'a Ast, id: ExpressionId, name: &str) {
+        let mut names = self.scopes.iter().flatten().copied();
+
+        if names.clone().any(|candidate| candidate == name) {
+            return;
+        }
+
+        let Some(help) = similarity::did_you_mean(name, &mut names) else {
+            return;
+        };
+
+        self.engine.report(diagnostic::Diagnostic::new(
+            diagnostic::Kind::Lint,
+            ast[id].span.clone(),
+            Message::new("lint-misspelled-name").with("name", name)
+        ).with_lint(&lint::MISSPELLED_NAMES).with_help(help));
+    }
+}
+
+impl<'a> Visitor<'a> for Names<'a, '_> {
+    fn visit_item(&mut self, ast: &'a Ast, id: ItemId) {
+        self.scoped(Vec::new(), |names| visit::walk_item(names, ast, id));
+    }
+
+    fn visit_statement(&mut self, ast: &'a Ast, id: StatementId) {
+        match &ast[id].kind {
+            StatementKind::Let(pattern, _, Some(value)) => {
+                self.visit_expression(ast, *value);
+                self.visit_pattern(ast, *pattern);
+            },
+
+            _ => visit::walk_statement(self, ast, id)
+        }
+    }
+
+    fn visit_expression(&mut self, ast: &'a Ast, id: ExpressionId) {
+        match &ast[id].kind {
+            ExpressionKind::Identifier(name) => self.check(ast, id, name),
+
+            ExpressionKind::Block(block) => {
+                let items = block.statements
+                    .iter()
+                    .filter_map(|statement| match &ast[*statement].kind {
+                        StatementKind::Item(item) => item_name(ast, *item),
+                        _ => None
+                    })
+                    .collect();
+
+                self.scoped(items, |names| visit::walk_expression(names, ast, id));
+            },
+
+            ExpressionKind::For(pattern, iterable, body) => {
+                self.visit_expression(ast, *iterable);
+
+                self.scoped(Vec::new(), |names| {
+                    names.visit_pattern(ast, *pattern);
+                    names.visit_expression(ast, *body);
+                });
+            },
+
+            _ => visit::walk_expression(self, ast, id)
+        }
+    }
+
+    fn visit_arm(&mut self, ast: &'a Ast, arm: &'a Arm) {
+        self.scoped(Vec::new(), |names| visit::walk_arm(names, ast, arm));
+    }
+
+    fn visit_pattern(&mut self, ast: &'a Ast, id: PatternId) {
+        if let (PatternKind::Binding(name), Some(scope)) = (&ast[id].kind, self.scopes.last_mut()) {
+            scope.push(name);
+        }
+
+        visit::walk_pattern(self, ast, id);
+    }
+}
+
+pub fn check(ast: &Ast, engine: &mut DiagnosticEngine) {
+    let items = ast.roots.iter().filter_map(|root| item_name(ast, *root)).collect();
+
+    let mut names = Names {
+        scopes: vec![items],
+        engine
+    };
+
+    visit::walk_ast(&mut names, ast);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::engine::DiagnosticEngine;
+    use crate::diagnostic::lint;
+    use crate::syntax::parser::Parser;
+    use crate::text::lexer::Lexer;
+
+    fn suggestions(content: &str) -> Vec<(String, usize)> {
+        let mut engine = DiagnosticEngine::quiet(lint::LintLevels::default());
+        let tokens = Lexer::new(String::from("test"), String::from(content)).tokenize(&mut engine);
+        let mut parser = Parser::new(&tokens);
+        parser.parse(&mut engine);
+        super::check(&parser.ast, &mut engine);
+
+        engine.diagnostics.iter().map(|diagnostic| (diagnostic.helps[0].to_string(), diagnostic.span.row)).collect()
+    }
+
+    #[test]
+    fn misspelled_names_suggest_names_in_scope() {
+        let content = "fn total(count: int) -> int {\n    let value = cuont;\n    for item in items(value) {\n        ietm;\n    }\n    match value {\n        other => other,\n    }\n    ohter;\n    totl(1)\n}\n";
+
+        assert_eq!(suggestions(content), [
+            (String::from("did you mean 'count'?"), 2),
+            (String::from("did you mean 'item'?"), 4),
+            (String::from("did you mean 'total'?"), 10)
+        ]);
+    }
+
+    #[test]
+    fn bindings_are_not_in_scope_in_their_own_initializer() {
+        assert_eq!(suggestions("fn main() {\n    let value = valeu;\n    value;\n}\n"), []);
+    }
+}
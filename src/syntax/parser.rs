@@ -0,0 +1,1140 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::message::Message;
+use crate::diagnostic::suggestion;
+use crate::syntax::ast::{self, Ast, BinaryOperator, ExpressionId, ExpressionKind, ItemId, ItemKind, Path, PatternId, PatternKind, StatementId, StatementKind, TypeId, TypeKind, UnaryOperator};
+use crate::text::keyword;
+use crate::text::token;
+use crate::utils::log;
+use crate::utils::span::Span;
+use std::ops::Range;
+
+pub struct Parser<'a> {
+    pub tokens: &'a [token::Token],
+    pub position: usize,
+    pub ast: Ast,
+    pub errors: Vec<diagnostic::Diagnostic>,
+    restricted: bool
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(tokens: &'a [token::Token]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            ast: Ast::new(),
+            errors: Vec::new(),
+            restricted: false
+        }
+    }
+
+    pub fn parse(&mut self, engine: &mut DiagnosticEngine) -> bool {
+        loop {
+            let attributes = self.parse_attributes();
+
+            if self.at(&token::Kind::EndOfFile) || self.current().is_none() {
+                break;
+            }
+
+            let start = self.position;
+
+            let parsed = match self.at_item() {
+                true => self.parse_item(),
+                false => Err(self.unexpected("parser-expected-item", self.token()))
+            };
+
+            let item = match parsed {
+                Ok(item) => item,
+
+                Err(diagnostic) => {
+                    self.errors.push(diagnostic);
+
+                    let span = self.synchronize(start, true);
+                    self.ast.item(ItemKind::Error, span)
+                }
+            };
+
+            self.ast.roots.push(item);
+            self.attach(attributes, self.ast[item].span.clone());
+        }
+
+        let clean = self.errors.is_empty();
+
+        for diagnostic in self.errors.drain(..) {
+            if engine.aborted() {
+                break;
+            }
+
+            engine.report(diagnostic);
+        }
+
+        clean
+    }
+
+    fn synchronize(&mut self, start: usize, item: bool) -> Span {
+        let mut depth = 0usize;
+
+        log::debug!("parser", "recovering from {:?}", self.current().map(|token| &token.raw));
+
+        for token in &self.tokens[start..self.position] {
+            match token.kind {
+                token::Kind::LeftCurlyBrace => depth += 1,
+                token::Kind::RightCurlyBrace => depth = depth.saturating_sub(1),
+                _ => ()
+            }
+        }
+
+        while let Some(token) = self.current() {
+            match token.kind {
+                token::Kind::EndOfFile => break,
+
+                token::Kind::LeftCurlyBrace => depth += 1,
+
+                token::Kind::RightCurlyBrace if depth == 0 => {
+                    if item {
+                        self.position += 1;
+                    }
+
+                    break;
+                },
+
+                token::Kind::RightCurlyBrace => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        self.position += 1;
+                        break;
+                    }
+                },
+
+                token::Kind::SemiColon if depth == 0 => {
+                    self.position += 1;
+                    break;
+                },
+
+                token::Kind::Identifier if depth == 0 && self.position > start && ["fn", "struct", "enum"].contains(&token.raw.as_str()) => break,
+
+                _ => ()
+            }
+
+            self.position += 1;
+        }
+
+        let first = self.tokens.get(start).map(|token| token.span.clone()).unwrap_or_else(|| self.end());
+
+        match self.position > start {
+            true => first.to(&self.previous()),
+            false => first
+        }
+    }
+
+    fn parse_attributes(&mut self) -> Range<usize> {
+        let first = self.ast.attributes.len();
+
+        while self.at(&token::Kind::Hash) {
+            let start = self.position;
+
+            if let Some(attribute) = self.parse_attribute() {
+                self.ast.attributes.push(attribute);
+                continue;
+            }
+
+            match self.tokens.get(start + 1).is_some_and(|token| token.kind == token::Kind::LeftSquareBrace) {
+                true => {
+                    let stops = [token::Kind::Hash, token::Kind::SemiColon, token::Kind::LeftCurlyBrace, token::Kind::RightCurlyBrace, token::Kind::EndOfFile];
+
+                    while self.current().is_some_and(|token| !stops.contains(&token.kind)) && !self.eat(&token::Kind::RightSquareBrace) {
+                        self.position += 1;
+                    }
+                },
+
+                false => self.position = start + 1
+            }
+
+            self.errors.push(diagnostic::Diagnostic::new(
+                diagnostic::Kind::SyntaxError,
+                self.tokens[start].span.to(&self.previous()),
+                Message::new("attribute-malformed")
+            ));
+        }
+
+        first..self.ast.attributes.len()
+    }
+
+    fn parse_attribute(&mut self) -> Option<ast::Attribute> {
+        let start = self.span();
+        self.position += 1;
+
+        if !self.eat(&token::Kind::LeftSquareBrace) || !self.at(&token::Kind::Identifier) {
+            return None;
+        }
+
+        let name = self.token().raw.clone();
+        self.position += 1;
+
+        if !self.eat(&token::Kind::LeftParenthesis) {
+            return None;
+        }
+
+        let mut arguments = Vec::new();
+
+        loop {
+            if !self.at(&token::Kind::Identifier) {
+                return None;
+            }
+
+            arguments.push((self.token().raw.clone(), self.span()));
+            self.position += 1;
+
+            if !self.eat(&token::Kind::Comma) {
+                break;
+            }
+        }
+
+        if !self.eat(&token::Kind::RightParenthesis) || !self.eat(&token::Kind::RightSquareBrace) {
+            return None;
+        }
+
+        let span = start.to(&self.previous());
+
+        Some(ast::Attribute {
+            name,
+            arguments,
+            end: span.index + span.length,
+            span
+        })
+    }
+
+    fn attach(&mut self, attributes: Range<usize>, target: Span) {
+        for attribute in &mut self.ast.attributes[attributes] {
+            attribute.end = target.index + target.length;
+        }
+    }
+
+    fn at_item(&self) -> bool {
+        self.at_keyword("fn") || self.at_keyword("struct") || self.at_keyword("enum")
+    }
+
+    fn parse_item(&mut self) -> Result<ItemId, diagnostic::Diagnostic> {
+        match self.token().raw.as_str() {
+            "struct" => self.parse_struct(),
+            "enum" => self.parse_enum(),
+            _ => self.parse_function()
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<&'a token::Token, diagnostic::Diagnostic> {
+        let name = self.token();
+
+        if name.kind != token::Kind::Identifier || keyword::is_keyword(&name.raw) {
+            return Err(self.unexpected("parser-expected-identifier", name));
+        }
+
+        self.position += 1;
+
+        Ok(name)
+    }
+
+    fn parse_struct(&mut self) -> Result<ItemId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let name = self.parse_name()?;
+        let fields = self.parse_fields()?;
+        let span = start.to(&self.previous());
+
+        let item = ast::Struct {
+            name: name.raw.clone(),
+            fields
+        };
+
+        Ok(self.ast.item(ItemKind::Struct(item), span))
+    }
+
+    fn parse_fields(&mut self) -> Result<Vec<ast::Field>, diagnostic::Diagnostic> {
+        let open = self.span();
+        self.expect(&token::Kind::LeftCurlyBrace, "{")?;
+
+        let mut fields = Vec::new();
+
+        while !self.at(&token::Kind::RightCurlyBrace) && !self.at(&token::Kind::EndOfFile) {
+            let field = self.parse_name()?;
+            self.expect(&token::Kind::Colon, ":")?;
+            let ty = self.parse_type()?;
+
+            fields.push(ast::Field {
+                name: field.raw.clone(),
+                ty,
+                span: field.span.to(&self.previous())
+            });
+
+            if !self.eat(&token::Kind::Comma) {
+                break;
+            }
+        }
+
+        self.close(open, token::Kind::RightCurlyBrace)?;
+
+        Ok(fields)
+    }
+
+    fn parse_enum(&mut self) -> Result<ItemId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let name = self.parse_name()?;
+        let open = self.span();
+        self.expect(&token::Kind::LeftCurlyBrace, "{")?;
+
+        let mut variants = Vec::new();
+
+        while !self.at(&token::Kind::RightCurlyBrace) && !self.at(&token::Kind::EndOfFile) {
+            let variant = self.parse_name()?;
+
+            let data = if self.at(&token::Kind::LeftCurlyBrace) {
+                ast::VariantData::Struct(self.parse_fields()?)
+            } else if self.at(&token::Kind::LeftParenthesis) {
+                let open = self.span();
+                self.position += 1;
+
+                let mut types = Vec::new();
+
+                while !self.at(&token::Kind::RightParenthesis) && !self.at(&token::Kind::EndOfFile) {
+                    types.push(self.parse_type()?);
+
+                    if !self.eat(&token::Kind::Comma) {
+                        break;
+                    }
+                }
+
+                self.close(open, token::Kind::RightParenthesis)?;
+
+                ast::VariantData::Tuple(types)
+            } else {
+                ast::VariantData::Unit
+            };
+
+            variants.push(ast::Variant {
+                name: variant.raw.clone(),
+                data,
+                span: variant.span.to(&self.previous())
+            });
+
+            if !self.eat(&token::Kind::Comma) {
+                break;
+            }
+        }
+
+        self.close(open, token::Kind::RightCurlyBrace)?;
+
+        let span = start.to(&self.previous());
+
+        let item = ast::Enum {
+            name: name.raw.clone(),
+            variants
+        };
+
+        Ok(self.ast.item(ItemKind::Enum(item), span))
+    }
+
+    fn parse_function(&mut self) -> Result<ItemId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let name = self.parse_name()?;
+        self.expect(&token::Kind::LeftParenthesis, "(")?;
+
+        let mut parameters = Vec::new();
+
+        while !self.at(&token::Kind::RightParenthesis) {
+            let pattern = self.parse_pattern()?;
+            self.expect(&token::Kind::Colon, ":")?;
+            let ty = self.parse_type()?;
+            let span = self.ast[pattern].span.to(&self.ast[ty].span);
+
+            parameters.push(ast::Parameter {
+                pattern,
+                ty,
+                span
+            });
+
+            if !self.eat(&token::Kind::Comma) {
+                break;
+            }
+        }
+
+        self.expect(&token::Kind::RightParenthesis, ")")?;
+
+        let output = match self.eat(&token::Kind::Arrow) {
+            true => Some(self.parse_type()?),
+            false => None
+        };
+
+        let body = self.parse_block()?;
+        let span = start.to(&self.previous());
+
+        let function = ast::Function {
+            name: name.raw.clone(),
+            parameters,
+            output,
+            body
+        };
+
+        Ok(self.ast.item(ItemKind::Function(function), span))
+    }
+
+    fn parse_statements(&mut self, end: token::Kind) -> ast::Block {
+        let mut statements = Vec::new();
+        let mut tail = None;
+
+        while !self.at(&end) && !self.at(&token::Kind::EndOfFile) {
+            let attributes = self.parse_attributes();
+
+            if self.at(&end) || self.at(&token::Kind::EndOfFile) {
+                break;
+            }
+
+            if self.eat(&token::Kind::SemiColon) {
+                continue;
+            }
+
+            let start = self.position;
+
+            let target = match self.parse_statement(&end, &mut tail) {
+                Ok(Some(statement)) => {
+                    statements.push(statement);
+                    self.ast[statement].span.clone()
+                },
+
+                Ok(None) => tail.map(|tail| self.ast[tail].span.clone()).unwrap_or_else(|| self.previous()),
+
+                Err(diagnostic) => {
+                    self.errors.push(diagnostic);
+
+                    let span = self.synchronize(start, false);
+                    let error = self.ast.expression(ExpressionKind::Error, span.clone());
+                    statements.push(self.ast.statement(StatementKind::Expression(error), span.clone()));
+                    span
+                }
+            };
+
+            self.attach(attributes, target);
+        }
+
+        ast::Block {
+            statements,
+            tail
+        }
+    }
+
+    fn parse_statement(&mut self, end: &token::Kind, tail: &mut Option<ExpressionId>) -> Result<Option<StatementId>, diagnostic::Diagnostic> {
+        if self.at_item() {
+            let item = self.parse_item()?;
+            let span = self.ast[item].span.clone();
+
+            return Ok(Some(self.ast.statement(StatementKind::Item(item), span)));
+        }
+
+        if self.at_keyword("let") {
+            return self.parse_let().map(Some);
+        }
+
+        let expression = match self.at_block_like() {
+            true => self.parse_prefix()?,
+            false => self.parse_expression(0)?
+        };
+
+        let span = self.ast[expression].span.clone();
+
+        if self.at(end) || self.at(&token::Kind::EndOfFile) {
+            *tail = Some(expression);
+            return Ok(None);
+        }
+
+        if !self.ast[expression].kind.is_block_like() {
+            self.terminate()?;
+        } else {
+            self.eat(&token::Kind::SemiColon);
+        }
+
+        let span = span.to(&self.previous());
+
+        Ok(Some(self.ast.statement(StatementKind::Expression(expression), span)))
+    }
+
+    fn terminate(&mut self) -> Result<(), diagnostic::Diagnostic> {
+        if self.eat(&token::Kind::SemiColon) {
+            return Ok(());
+        }
+
+        let mut after = self.previous();
+        after.index += after.length;
+        after.column += after.length;
+
+        let diagnostic = self.expected_token(";").with_suggestion(suggestion::Suggestion::new(
+            Message::new("parser-insert-semicolon"),
+            vec![suggestion::Edit::insert(after, String::from(";"))],
+            suggestion::Applicability::MaybeIncorrect
+        ));
+
+        if self.span().row > self.previous().row {
+            self.errors.push(diagnostic);
+            return Ok(());
+        }
+
+        Err(diagnostic)
+    }
+
+    fn parse_let(&mut self) -> Result<StatementId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let pattern = self.parse_pattern()?;
+
+        let ty = match self.eat(&token::Kind::Colon) {
+            true => Some(self.parse_type()?),
+            false => None
+        };
+
+        let value = match self.eat(&token::Kind::Assign) {
+            true => Some(self.parse_expression(0)?),
+            false => None
+        };
+
+        self.terminate()?;
+
+        let span = start.to(&self.previous());
+
+        Ok(self.ast.statement(StatementKind::Let(pattern, ty, value), span))
+    }
+
+    fn parse_pattern(&mut self) -> Result<PatternId, diagnostic::Diagnostic> {
+        let token = self.token();
+        let start = token.span.clone();
+
+        let literal = match token.kind {
+            token::Kind::Integer | token::Kind::Float | token::Kind::String | token::Kind::Char => true,
+            token::Kind::Minus => self.tokens.get(self.position + 1).is_some_and(|next| matches!(next.kind, token::Kind::Integer | token::Kind::Float)),
+            token::Kind::Identifier => token.raw == "true" || token.raw == "false",
+            _ => false
+        };
+
+        if literal {
+            let value = self.parse_prefix()?;
+            let span = self.ast[value].span.clone();
+
+            return Ok(self.ast.pattern(PatternKind::Literal(value), span));
+        }
+
+        if token.kind == token::Kind::LeftParenthesis {
+            self.position += 1;
+
+            let mut elements = Vec::new();
+            let mut trailing = false;
+
+            while !self.at(&token::Kind::RightParenthesis) && !self.at(&token::Kind::EndOfFile) {
+                elements.push(self.parse_pattern()?);
+                trailing = self.eat(&token::Kind::Comma);
+
+                if !trailing {
+                    break;
+                }
+            }
+
+            self.close(start.clone(), token::Kind::RightParenthesis)?;
+
+            if elements.len() == 1 && !trailing {
+                return Ok(elements[0]);
+            }
+
+            let span = start.to(&self.previous());
+
+            return Ok(self.ast.pattern(PatternKind::Tuple(elements), span));
+        }
+
+        if token.kind == token::Kind::Identifier && token.raw == "_" {
+            self.position += 1;
+            return Ok(self.ast.pattern(PatternKind::Wildcard, start));
+        }
+
+        if token.kind != token::Kind::Identifier || keyword::is_keyword(&token.raw) {
+            return Err(self.unexpected("parser-expected-pattern", token));
+        }
+
+        let path = self.parse_path()?;
+
+        let kind = if self.at(&token::Kind::LeftParenthesis) {
+            let open = self.span();
+            self.position += 1;
+
+            let mut elements = Vec::new();
+
+            while !self.at(&token::Kind::RightParenthesis) && !self.at(&token::Kind::EndOfFile) {
+                elements.push(self.parse_pattern()?);
+
+                if !self.eat(&token::Kind::Comma) {
+                    break;
+                }
+            }
+
+            self.close(open, token::Kind::RightParenthesis)?;
+
+            PatternKind::TupleVariant(path, elements)
+        } else if self.at(&token::Kind::LeftCurlyBrace) {
+            let open = self.span();
+            self.position += 1;
+
+            let mut fields = Vec::new();
+
+            while !self.at(&token::Kind::RightCurlyBrace) && !self.at(&token::Kind::EndOfFile) {
+                let field = self.parse_name()?;
+
+                let pattern = match self.eat(&token::Kind::Colon) {
+                    true => self.parse_pattern()?,
+                    false => self.ast.pattern(PatternKind::Binding(field.raw.clone()), field.span.clone())
+                };
+
+                fields.push(ast::FieldPattern {
+                    name: field.raw.clone(),
+                    pattern,
+                    span: field.span.to(&self.previous())
+                });
+
+                if !self.eat(&token::Kind::Comma) {
+                    break;
+                }
+            }
+
+            self.close(open, token::Kind::RightCurlyBrace)?;
+
+            PatternKind::Struct(path, fields)
+        } else if path.len() == 1 {
+            PatternKind::Binding(path[0].clone())
+        } else {
+            PatternKind::Path(path)
+        };
+
+        let span = start.to(&self.previous());
+
+        Ok(self.ast.pattern(kind, span))
+    }
+
+    fn parse_path(&mut self) -> Result<Path, diagnostic::Diagnostic> {
+        let mut path = vec![self.parse_name()?.raw.clone()];
+
+        while self.eat(&token::Kind::ColonColon) {
+            path.push(self.parse_name()?.raw.clone());
+        }
+
+        Ok(path)
+    }
+
+    fn parse_type(&mut self) -> Result<TypeId, diagnostic::Diagnostic> {
+        let token = self.token();
+
+        if token.kind != token::Kind::Identifier || keyword::is_keyword(&token.raw) {
+            return Err(self.unexpected("parser-expected-type", token));
+        }
+
+        self.position += 1;
+
+        Ok(self.ast.ty(TypeKind::Named(token.raw.clone()), token.span.clone()))
+    }
+
+    fn parse_condition(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let restricted = self.restricted;
+        self.restricted = true;
+
+        let condition = self.parse_expression(0);
+        self.restricted = restricted;
+
+        condition
+    }
+
+    fn parse_block(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let open = self.span();
+        self.expect(&token::Kind::LeftCurlyBrace, "{")?;
+
+        let restricted = self.restricted;
+        self.restricted = false;
+
+        let block = self.parse_statements(token::Kind::RightCurlyBrace);
+        self.restricted = restricted;
+
+        if !self.eat(&token::Kind::RightCurlyBrace) {
+            return Err(self.unclosed(open, "parser-unclosed-block", "parser-close-block", "}"));
+        }
+
+        let span = open.to(&self.previous());
+
+        Ok(self.ast.expression(ExpressionKind::Block(block), span))
+    }
+
+    fn parse_if(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let condition = self.parse_condition()?;
+        let then = self.parse_block()?;
+
+        let otherwise = match self.eat_keyword("else") {
+            true if self.at_keyword("if") => Some(self.parse_if()?),
+            true => Some(self.parse_block()?),
+            false => None
+        };
+
+        let span = start.to(&self.previous());
+
+        Ok(self.ast.expression(ExpressionKind::If(condition, then, otherwise), span))
+    }
+
+    fn parse_while(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let condition = self.parse_condition()?;
+        let body = self.parse_block()?;
+        let span = start.to(&self.previous());
+
+        Ok(self.ast.expression(ExpressionKind::While(condition, body), span))
+    }
+
+    fn parse_for(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let pattern = self.parse_pattern()?;
+
+        if !self.eat_keyword("in") {
+            return Err(self.expected_token("in"));
+        }
+
+        let iterable = self.parse_condition()?;
+        let body = self.parse_block()?;
+        let span = start.to(&self.previous());
+
+        Ok(self.ast.expression(ExpressionKind::For(pattern, iterable, body), span))
+    }
+
+    fn parse_match(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let scrutinee = self.parse_condition()?;
+        let open = self.span();
+        self.expect(&token::Kind::LeftCurlyBrace, "{")?;
+
+        let restricted = self.restricted;
+        self.restricted = false;
+
+        let arms = self.parse_arms();
+        self.restricted = restricted;
+
+        let arms = arms?;
+        self.close(open, token::Kind::RightCurlyBrace)?;
+
+        let span = start.to(&self.previous());
+
+        Ok(self.ast.expression(ExpressionKind::Match(scrutinee, arms), span))
+    }
+
+    fn parse_arms(&mut self) -> Result<Vec<ast::Arm>, diagnostic::Diagnostic> {
+        let mut arms = Vec::new();
+
+        while !self.at(&token::Kind::RightCurlyBrace) && !self.at(&token::Kind::EndOfFile) {
+            let start = self.span();
+            let pattern = self.parse_pattern()?;
+
+            let guard = match self.eat_keyword("if") {
+                true => Some(self.parse_expression(0)?),
+                false => None
+            };
+
+            self.expect(&token::Kind::FatArrow, "=>")?;
+
+            let body = match self.at_block_like() {
+                true => self.parse_prefix()?,
+                false => self.parse_expression(0)?
+            };
+
+            let span = start.to(&self.previous());
+
+            arms.push(ast::Arm {
+                pattern,
+                guard,
+                body,
+                span
+            });
+
+            if !self.eat(&token::Kind::Comma) && !self.ast[body].kind.is_block_like() && !self.at(&token::Kind::RightCurlyBrace) {
+                return Err(self.expected_token(","));
+            }
+        }
+
+        Ok(arms)
+    }
+
+    fn parse_return(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let start = self.span();
+        self.position += 1;
+
+        let ends = [
+            token::Kind::SemiColon,
+            token::Kind::Comma,
+            token::Kind::RightParenthesis,
+            token::Kind::RightSquareBrace,
+            token::Kind::RightCurlyBrace,
+            token::Kind::FatArrow,
+            token::Kind::EndOfFile
+        ];
+
+        let value = match ends.iter().any(|kind| self.at(kind)) {
+            true => None,
+            false => Some(self.parse_expression(0)?)
+        };
+
+        let span = start.to(&self.previous());
+
+        Ok(self.ast.expression(ExpressionKind::Return(value), span))
+    }
+
+    pub fn parse_expression(&mut self, minimum: u8) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let prefix = self.parse_prefix()?;
+        let mut left = self.parse_postfix(prefix)?;
+
+        while let Some(operator) = self.current().and_then(|token| BinaryOperator::from_token(&token.kind)) {
+            let precedence = operator.precedence();
+
+            if precedence < minimum {
+                break;
+            }
+
+            log::trace!("parser", "binary {} at precedence {}", operator.symbol(), precedence);
+
+            self.position += 1;
+            let right = self.parse_expression(precedence + 1)?;
+            let span = self.ast[left].span.to(&self.ast[right].span);
+
+            left = self.ast.expression(ExpressionKind::Binary(left, operator, right), span);
+        }
+
+        if minimum == 0 {
+            if let Some(operator) = self.current().and_then(|token| BinaryOperator::from_assignment(&token.kind)) {
+                if !self.ast[left].kind.is_place() {
+                    return Err(diagnostic::Diagnostic::new(
+                        diagnostic::Kind::SyntaxError,
+                        self.ast[left].span.clone(),
+                        Message::new("parser-invalid-assignment")
+                    ));
+                }
+
+                self.position += 1;
+                let value = self.parse_expression(0)?;
+                let span = self.ast[left].span.to(&self.ast[value].span);
+
+                left = self.ast.expression(ExpressionKind::Assign(left, operator, value), span);
+            }
+        }
+
+        Ok(left)
+    }
+
+    fn parse_postfix(&mut self, mut expression: ExpressionId) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        loop {
+            if self.eat(&token::Kind::Dot) {
+                let field = self.parse_name()?;
+                let span = self.ast[expression].span.to(&field.span);
+
+                expression = self.ast.expression(ExpressionKind::Field(expression, field.raw.clone()), span);
+                continue;
+            }
+
+            if !self.at(&token::Kind::LeftParenthesis) {
+                return Ok(expression);
+            }
+
+            let open = self.span();
+            self.position += 1;
+
+            let restricted = self.restricted;
+            self.restricted = false;
+
+            let arguments = self.parse_arguments();
+            self.restricted = restricted;
+
+            let arguments = arguments?;
+
+            if !self.eat(&token::Kind::RightParenthesis) {
+                return Err(self.unclosed(open, "parser-unclosed-group", "parser-close-group", ")"));
+            }
+
+            let span = self.ast[expression].span.to(&self.previous());
+            expression = self.ast.expression(ExpressionKind::Call(expression, arguments), span);
+        }
+    }
+
+    fn parse_arguments(&mut self) -> Result<Vec<ExpressionId>, diagnostic::Diagnostic> {
+        let mut arguments = Vec::new();
+
+        while !self.at(&token::Kind::RightParenthesis) && !self.at(&token::Kind::EndOfFile) {
+            arguments.push(self.parse_expression(0)?);
+
+            if !self.eat(&token::Kind::Comma) {
+                break;
+            }
+        }
+
+        Ok(arguments)
+    }
+
+    fn parse_struct_literal(&mut self, start: Span, path: Path) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let open = self.span();
+        self.position += 1;
+
+        let mut fields = Vec::new();
+
+        while !self.at(&token::Kind::RightCurlyBrace) && !self.at(&token::Kind::EndOfFile) {
+            let field = self.parse_name()?;
+
+            let value = match self.eat(&token::Kind::Colon) {
+                true => self.parse_expression(0)?,
+                false => self.ast.expression(ExpressionKind::Identifier(field.raw.clone()), field.span.clone())
+            };
+
+            fields.push(ast::FieldValue {
+                name: field.raw.clone(),
+                value,
+                span: field.span.to(&self.previous())
+            });
+
+            if !self.eat(&token::Kind::Comma) {
+                break;
+            }
+        }
+
+        self.close(open, token::Kind::RightCurlyBrace)?;
+
+        let span = start.to(&self.previous());
+
+        Ok(self.ast.expression(ExpressionKind::Struct(path, fields), span))
+    }
+
+    fn parse_prefix(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let Some(token) = self.current() else {
+            return Err(self.end_of_input("parser-expected-expression"));
+        };
+
+        let span = token.span.clone();
+
+        if let Some(operator) = UnaryOperator::from_token(&token.kind) {
+            self.position += 1;
+            let operand = self.parse_expression(ast::PREFIX_PRECEDENCE)?;
+            let span = span.to(&self.ast[operand].span);
+
+            return Ok(self.ast.expression(ExpressionKind::Unary(operator, operand), span));
+        }
+
+        let kind = match token.kind {
+            token::Kind::Integer => ExpressionKind::Integer(token.raw.clone()),
+            token::Kind::Float => ExpressionKind::Float(token.raw.clone()),
+            token::Kind::String => ExpressionKind::String(token.raw.clone()),
+            token::Kind::Char => ExpressionKind::Char(token.raw.clone()),
+            token::Kind::Boolean => ExpressionKind::Boolean(token.raw == "true"),
+
+            token::Kind::Identifier => match token.raw.as_str() {
+                "true" => ExpressionKind::Boolean(true),
+                "false" => ExpressionKind::Boolean(false),
+                "break" => ExpressionKind::Break,
+                "continue" => ExpressionKind::Continue,
+                "if" => return self.parse_if(),
+                "while" => return self.parse_while(),
+                "for" => return self.parse_for(),
+                "return" => return self.parse_return(),
+                "match" => return self.parse_match(),
+                name if keyword::is_keyword(name) => return Err(self.unexpected("parser-expected-expression", token)),
+                _ => return self.parse_path_expression()
+            },
+
+            token::Kind::LeftParenthesis => return self.parse_group(),
+            token::Kind::LeftCurlyBrace => return self.parse_block(),
+
+            _ => return Err(self.unexpected("parser-expected-expression", token))
+        };
+
+        self.position += 1;
+
+        Ok(self.ast.expression(kind, span))
+    }
+
+    fn parse_path_expression(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let start = self.span();
+        let mut path = self.parse_path()?;
+
+        if !self.restricted && self.at(&token::Kind::LeftCurlyBrace) {
+            return self.parse_struct_literal(start, path);
+        }
+
+        let span = start.to(&self.previous());
+
+        let kind = match path.len() {
+            1 => ExpressionKind::Identifier(path.remove(0)),
+            _ => ExpressionKind::Path(path)
+        };
+
+        Ok(self.ast.expression(kind, span))
+    }
+
+    fn parse_group(&mut self) -> Result<ExpressionId, diagnostic::Diagnostic> {
+        let open = self.span();
+        self.position += 1;
+
+        let restricted = self.restricted;
+        self.restricted = false;
+
+        let elements = self.parse_elements();
+        self.restricted = restricted;
+
+        let (mut elements, trailing) = elements?;
+        self.close(open.clone(), token::Kind::RightParenthesis)?;
+
+        let span = open.to(&self.previous());
+
+        let kind = match elements.len() {
+            1 if !trailing => ExpressionKind::Group(elements.remove(0)),
+            _ => ExpressionKind::Tuple(elements)
+        };
+
+        Ok(self.ast.expression(kind, span))
+    }
+
+    fn parse_elements(&mut self) -> Result<(Vec<ExpressionId>, bool), diagnostic::Diagnostic> {
+        let mut elements = Vec::new();
+        let mut trailing = false;
+
+        while !self.at(&token::Kind::RightParenthesis) && !self.at(&token::Kind::EndOfFile) {
+            elements.push(self.parse_expression(0)?);
+            trailing = self.eat(&token::Kind::Comma);
+
+            if !trailing {
+                break;
+            }
+        }
+
+        Ok((elements, trailing))
+    }
+
+    fn current(&self) -> Option<&'a token::Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn token(&self) -> &'a token::Token {
+        self.current().or(self.tokens.last()).expect("the lexer always produces at least one token")
+    }
+
+    fn span(&self) -> Span {
+        self.current().map(|token| token.span.clone()).unwrap_or_else(|| self.end())
+    }
+
+    fn previous(&self) -> Span {
+        match self.position {
+            0 => self.span(),
+            position => self.tokens[position - 1].span.clone()
+        }
+    }
+
+    fn end(&self) -> Span {
+        self.tokens.last().map(|token| token.span.clone()).unwrap_or_default()
+    }
+
+    fn at(&self, kind: &token::Kind) -> bool {
+        self.current().is_some_and(|token| token.kind == *kind)
+    }
+
+    fn at_block_like(&self) -> bool {
+        self.at(&token::Kind::LeftCurlyBrace) || ["if", "while", "for", "match"].iter().any(|name| self.at_keyword(name))
+    }
+
+    fn at_keyword(&self, name: &str) -> bool {
+        self.current().is_some_and(|token| token.kind == token::Kind::Identifier && token.raw == name)
+    }
+
+    fn eat(&mut self, kind: &token::Kind) -> bool {
+        let found = self.at(kind);
+
+        if found {
+            self.position += 1;
+        }
+
+        found
+    }
+
+    fn eat_keyword(&mut self, name: &str) -> bool {
+        let found = self.at_keyword(name);
+
+        if found {
+            self.position += 1;
+        }
+
+        found
+    }
+
+    fn expect(&mut self, kind: &token::Kind, text: &str) -> Result<(), diagnostic::Diagnostic> {
+        match self.eat(kind) {
+            true => Ok(()),
+            false => Err(self.expected_token(text))
+        }
+    }
+
+    fn found(token: &token::Token) -> Message {
+        match token.kind {
+            token::Kind::EndOfFile => Message::new("parser-found-end"),
+            _ => Message::new("parser-found-token").with("token", &token.raw)
+        }
+    }
+
+    fn unexpected(&self, id: &'static str, token: &token::Token) -> diagnostic::Diagnostic {
+        diagnostic::Diagnostic::new(diagnostic::Kind::SyntaxError, token.span.clone(), Message::new(id).with("found", Self::found(token)))
+    }
+
+    fn expected_token(&self, text: &str) -> diagnostic::Diagnostic {
+        let token = self.token();
+
+        diagnostic::Diagnostic::new(
+            diagnostic::Kind::SyntaxError,
+            token.span.clone(),
+            Message::new("parser-expected-token").with("token", text).with("found", Self::found(token))
+        )
+    }
+
+    fn unclosed(&self, open: Span, id: &'static str, help: &'static str, close: &str) -> diagnostic::Diagnostic {
+        diagnostic::Diagnostic::new(
+            diagnostic::Kind::SyntaxError,
+            open,
+            Message::new(id)
+        ).with_suggestion(suggestion::Suggestion::new(
+            Message::new(help),
+            vec![suggestion::Edit::insert(self.span(), String::from(close))],
+            suggestion::Applicability::MaybeIncorrect
+        ))
+    }
+
+    fn close(&mut self, open: Span, kind: token::Kind) -> Result<(), diagnostic::Diagnostic> {
+        let (text, id, help) = match kind {
+            token::Kind::RightParenthesis => (")", "parser-unclosed-group", "parser-close-group"),
+            _ => ("}", "parser-unclosed-block", "parser-close-block")
+        };
+
+        if self.eat(&kind) {
+            return Ok(());
+        }
+
+        match self.at(&token::Kind::EndOfFile) {
+            true => Err(self.unclosed(open, id, help, text)),
+            false => Err(self.expected_token(text))
+        }
+    }
+
+    fn end_of_input(&self, id: &'static str) -> diagnostic::Diagnostic {
+        diagnostic::Diagnostic::new(diagnostic::Kind::SyntaxError, self.end(), Message::new(id).with("found", Message::new("parser-found-end")))
+    }
+}
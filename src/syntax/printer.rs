@@ -0,0 +1,660 @@
+use crate::syntax::ast::{self, Ast, ExpressionId, ExpressionKind, ItemId, ItemKind, PatternId, PatternKind, StatementId, StatementKind, TypeId, TypeKind, VariantData};
+
+const ASSIGNMENT: u8 = 0;
+const POSTFIX: u8 = ast::PREFIX_PRECEDENCE + 1;
+const PRIMARY: u8 = POSTFIX + 1;
+
+pub struct Printer<'a> {
+    pub ast: &'a Ast,
+    pub output: String,
+    indent: usize
+}
+
+pub fn source(ast: &Ast) -> String {
+    let mut printer = Printer::new(ast);
+
+    for (index, root) in ast.roots.iter().enumerate() {
+        if index > 0 {
+            printer.output.push('\n');
+        }
+
+        printer.item(*root);
+        printer.output.push('\n');
+    }
+
+    printer.output
+}
+
+pub fn expression(ast: &Ast, id: ExpressionId) -> String {
+    let mut printer = Printer::new(ast);
+    printer.expression(id, ASSIGNMENT);
+    printer.output
+}
+
+fn precedence(ast: &Ast, id: ExpressionId) -> u8 {
+    match &ast[id].kind {
+        ExpressionKind::Group(inner) => precedence(ast, *inner),
+        ExpressionKind::Assign(..) | ExpressionKind::Return(_) => ASSIGNMENT,
+        ExpressionKind::Binary(_, operator, _) => operator.precedence(),
+        ExpressionKind::Unary(..) => ast::PREFIX_PRECEDENCE,
+        ExpressionKind::Call(..) | ExpressionKind::Field(..) => POSTFIX,
+        _ => PRIMARY
+    }
+}
+
+fn starts_with_block(ast: &Ast, id: ExpressionId) -> bool {
+    match &ast[id].kind {
+        kind if kind.is_block_like() => true,
+        ExpressionKind::Group(inner) => starts_with_block(ast, *inner),
+
+        ExpressionKind::Binary(left, ..) | ExpressionKind::Assign(left, ..) | ExpressionKind::Call(left, _) | ExpressionKind::Field(left, _) => {
+            starts_with_block(ast, *left)
+        },
+
+        _ => false
+    }
+}
+
+fn ends_with_return(ast: &Ast, id: ExpressionId) -> bool {
+    match &ast[id].kind {
+        ExpressionKind::Return(None) => true,
+        ExpressionKind::Group(inner) | ExpressionKind::Return(Some(inner)) | ExpressionKind::Assign(_, _, inner) => ends_with_return(ast, *inner),
+        _ => false
+    }
+}
+
+fn ends_with_dot(ast: &Ast, id: ExpressionId) -> bool {
+    match &ast[id].kind {
+        ExpressionKind::Float(value) => value.ends_with('.'),
+        ExpressionKind::Group(inner) => ends_with_dot(ast, *inner),
+        _ => false
+    }
+}
+
+fn contains_struct(ast: &Ast, id: ExpressionId) -> bool {
+    match &ast[id].kind {
+        ExpressionKind::Struct(..) => true,
+
+        ExpressionKind::Group(inner) | ExpressionKind::Unary(_, inner) | ExpressionKind::Field(inner, _) | ExpressionKind::Call(inner, _) => {
+            contains_struct(ast, *inner)
+        },
+
+        ExpressionKind::Binary(left, _, right) | ExpressionKind::Assign(left, _, right) => contains_struct(ast, *left) || contains_struct(ast, *right),
+        ExpressionKind::Return(Some(value)) => contains_struct(ast, *value),
+        _ => false
+    }
+}
+
+impl<'a> Printer<'a> {
+    pub fn new(ast: &'a Ast) -> Self {
+        Self {
+            ast,
+            output: String::new(),
+            indent: 0
+        }
+    }
+
+    fn line(&mut self) {
+        self.output.push('\n');
+        self.output.push_str(&"    ".repeat(self.indent));
+    }
+
+    fn list<T: Copy>(&mut self, elements: &[T], mut print: impl FnMut(&mut Self, T)) {
+        for (index, element) in elements.iter().enumerate() {
+            if index > 0 {
+                self.output.push_str(", ");
+            }
+
+            print(self, *element);
+        }
+    }
+
+    pub fn item(&mut self, id: ItemId) {
+        let ast = self.ast;
+
+        match &ast[id].kind {
+            ItemKind::Function(function) => {
+                self.output.push_str("fn ");
+                self.output.push_str(&function.name);
+                self.output.push('(');
+
+                for (index, parameter) in function.parameters.iter().enumerate() {
+                    if index > 0 {
+                        self.output.push_str(", ");
+                    }
+
+                    self.pattern(parameter.pattern);
+                    self.output.push_str(": ");
+                    self.ty(parameter.ty);
+                }
+
+                self.output.push(')');
+
+                if let Some(output) = function.output {
+                    self.output.push_str(" -> ");
+                    self.ty(output);
+                }
+
+                self.output.push(' ');
+                self.expression(function.body, PRIMARY);
+            },
+
+            ItemKind::Struct(item) => {
+                self.output.push_str("struct ");
+                self.output.push_str(&item.name);
+                self.output.push(' ');
+                self.fields(&item.fields);
+            },
+
+            ItemKind::Enum(item) => {
+                self.output.push_str("enum ");
+                self.output.push_str(&item.name);
+
+                if item.variants.is_empty() {
+                    self.output.push_str(" {}");
+                    return;
+                }
+
+                self.output.push_str(" {");
+                self.indent += 1;
+
+                for (index, variant) in item.variants.iter().enumerate() {
+                    if index > 0 {
+                        self.output.push(',');
+                    }
+
+                    self.line();
+                    self.output.push_str(&variant.name);
+
+                    match &variant.data {
+                        VariantData::Unit => (),
+
+                        VariantData::Tuple(types) => {
+                            self.output.push('(');
+                            self.list(types, Self::ty);
+                            self.output.push(')');
+                        },
+
+                        VariantData::Struct(fields) => {
+                            self.output.push(' ');
+                            self.fields(fields);
+                        }
+                    }
+                }
+
+                self.indent -= 1;
+                self.line();
+                self.output.push('}');
+            },
+
+            ItemKind::Error => self.output.push_str("/* error */")
+        }
+    }
+
+    fn fields(&mut self, fields: &[ast::Field]) {
+        if fields.is_empty() {
+            self.output.push_str("{}");
+            return;
+        }
+
+        self.output.push('{');
+        self.indent += 1;
+
+        for (index, field) in fields.iter().enumerate() {
+            if index > 0 {
+                self.output.push(',');
+            }
+
+            self.line();
+            self.output.push_str(&field.name);
+            self.output.push_str(": ");
+            self.ty(field.ty);
+        }
+
+        self.indent -= 1;
+        self.line();
+        self.output.push('}');
+    }
+
+    pub fn statement(&mut self, id: StatementId) {
+        let ast = self.ast;
+
+        match &ast[id].kind {
+            StatementKind::Let(pattern, annotation, value) => {
+                self.output.push_str("let ");
+                self.pattern(*pattern);
+
+                if let Some(annotation) = annotation {
+                    self.output.push_str(": ");
+                    self.ty(*annotation);
+                }
+
+                if let Some(value) = value {
+                    self.output.push_str(" = ");
+                    self.expression(*value, ASSIGNMENT);
+                }
+
+                self.output.push(';');
+            },
+
+            StatementKind::Expression(value) => {
+                self.leading(*value);
+
+                if !ast[*value].kind.is_block_like() {
+                    self.output.push(';');
+                }
+            },
+
+            StatementKind::Item(item) => self.item(*item)
+        }
+    }
+
+    fn leading(&mut self, id: ExpressionId) {
+        match !self.ast[id].kind.is_block_like() && starts_with_block(self.ast, id) {
+            true => self.parenthesized(id),
+            false => self.expression(id, ASSIGNMENT)
+        }
+    }
+
+    fn restricted(&mut self, id: ExpressionId) {
+        match contains_struct(self.ast, id) || ends_with_return(self.ast, id) {
+            true => self.parenthesized(id),
+            false => self.expression(id, ASSIGNMENT)
+        }
+    }
+
+    fn postfix(&mut self, id: ExpressionId) {
+        match ends_with_dot(self.ast, id) {
+            true => self.parenthesized(id),
+            false => self.expression(id, POSTFIX)
+        }
+    }
+
+    fn parenthesized(&mut self, id: ExpressionId) {
+        self.output.push('(');
+        self.expression(id, ASSIGNMENT);
+        self.output.push(')');
+    }
+
+    pub fn expression(&mut self, id: ExpressionId, minimum: u8) {
+        let ast = self.ast;
+
+        if precedence(ast, id) < minimum {
+            self.parenthesized(id);
+            return;
+        }
+
+        match &ast[id].kind {
+            ExpressionKind::Integer(value) | ExpressionKind::Float(value) | ExpressionKind::String(value) | ExpressionKind::Char(value) => {
+                self.output.push_str(value)
+            },
+
+            ExpressionKind::Boolean(value) => self.output.push_str(if *value { "true" } else { "false" }),
+            ExpressionKind::Identifier(name) => self.output.push_str(name),
+            ExpressionKind::Path(path) => self.output.push_str(&path.join("::")),
+            ExpressionKind::Group(inner) => self.expression(*inner, minimum),
+
+            ExpressionKind::Tuple(elements) => {
+                self.output.push('(');
+                self.list(elements, |printer, element| printer.expression(element, ASSIGNMENT));
+
+                if elements.len() == 1 {
+                    self.output.push(',');
+                }
+
+                self.output.push(')');
+            },
+
+            ExpressionKind::Unary(operator, operand) => {
+                self.output.push_str(operator.symbol());
+                self.expression(*operand, ast::PREFIX_PRECEDENCE);
+            },
+
+            ExpressionKind::Binary(left, operator, right) => {
+                let precedence = operator.precedence();
+
+                self.expression(*left, precedence);
+                self.output.push(' ');
+                self.output.push_str(operator.symbol());
+                self.output.push(' ');
+                self.expression(*right, precedence + 1);
+            },
+
+            ExpressionKind::Call(callee, arguments) => {
+                self.postfix(*callee);
+                self.output.push('(');
+                self.list(arguments, |printer, argument| printer.expression(argument, ASSIGNMENT));
+                self.output.push(')');
+            },
+
+            ExpressionKind::Field(target, name) => {
+                self.postfix(*target);
+                self.output.push('.');
+                self.output.push_str(name);
+            },
+
+            ExpressionKind::Struct(path, fields) => {
+                self.output.push_str(&path.join("::"));
+
+                if fields.is_empty() {
+                    self.output.push_str(" {}");
+                    return;
+                }
+
+                self.output.push_str(" { ");
+
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        self.output.push_str(", ");
+                    }
+
+                    self.output.push_str(&field.name);
+
+                    if !matches!(&ast[field.value].kind, ExpressionKind::Identifier(name) if *name == field.name) {
+                        self.output.push_str(": ");
+                        self.expression(field.value, ASSIGNMENT);
+                    }
+                }
+
+                self.output.push_str(" }");
+            },
+
+            ExpressionKind::Assign(target, operator, value) => {
+                self.expression(*target, POSTFIX);
+                self.output.push(' ');
+                self.output.push_str(operator.map(|operator| operator.symbol()).unwrap_or_default());
+                self.output.push_str("= ");
+                self.expression(*value, ASSIGNMENT);
+            },
+
+            ExpressionKind::Block(block) => {
+                if block.statements.is_empty() && block.tail.is_none() {
+                    self.output.push_str("{}");
+                    return;
+                }
+
+                self.output.push('{');
+                self.indent += 1;
+
+                for statement in &block.statements {
+                    self.line();
+                    self.statement(*statement);
+                }
+
+                if let Some(tail) = block.tail {
+                    self.line();
+                    self.leading(tail);
+                }
+
+                self.indent -= 1;
+                self.line();
+                self.output.push('}');
+            },
+
+            ExpressionKind::If(condition, then, otherwise) => {
+                self.output.push_str("if ");
+                self.restricted(*condition);
+                self.output.push(' ');
+                self.expression(*then, PRIMARY);
+
+                if let Some(otherwise) = otherwise {
+                    self.output.push_str(" else ");
+                    self.expression(*otherwise, PRIMARY);
+                }
+            },
+
+            ExpressionKind::While(condition, body) => {
+                self.output.push_str("while ");
+                self.restricted(*condition);
+                self.output.push(' ');
+                self.expression(*body, PRIMARY);
+            },
+
+            ExpressionKind::For(binding, iterable, body) => {
+                self.output.push_str("for ");
+                self.pattern(*binding);
+                self.output.push_str(" in ");
+                self.restricted(*iterable);
+                self.output.push(' ');
+                self.expression(*body, PRIMARY);
+            },
+
+            ExpressionKind::Break => self.output.push_str("break"),
+            ExpressionKind::Continue => self.output.push_str("continue"),
+
+            ExpressionKind::Return(value) => {
+                self.output.push_str("return");
+
+                if let Some(value) = value {
+                    self.output.push(' ');
+                    self.expression(*value, ASSIGNMENT);
+                }
+            },
+
+            ExpressionKind::Match(scrutinee, arms) => {
+                self.output.push_str("match ");
+                self.restricted(*scrutinee);
+
+                if arms.is_empty() {
+                    self.output.push_str(" {}");
+                    return;
+                }
+
+                self.output.push_str(" {");
+                self.indent += 1;
+
+                for arm in arms {
+                    self.line();
+                    self.pattern(arm.pattern);
+
+                    if let Some(guard) = arm.guard {
+                        self.output.push_str(" if ");
+                        self.expression(guard, ASSIGNMENT);
+                    }
+
+                    self.output.push_str(" => ");
+                    self.leading(arm.body);
+
+                    if !ast[arm.body].kind.is_block_like() {
+                        self.output.push(',');
+                    }
+                }
+
+                self.indent -= 1;
+                self.line();
+                self.output.push('}');
+            },
+
+            ExpressionKind::Error => self.output.push_str("/* error */")
+        }
+    }
+
+    pub fn pattern(&mut self, id: PatternId) {
+        let ast = self.ast;
+
+        match &ast[id].kind {
+            PatternKind::Wildcard => self.output.push('_'),
+            PatternKind::Binding(name) => self.output.push_str(name),
+            PatternKind::Literal(value) => self.expression(*value, ASSIGNMENT),
+            PatternKind::Path(path) => self.output.push_str(&path.join("::")),
+
+            PatternKind::Tuple(elements) => {
+                self.output.push('(');
+                self.list(elements, Self::pattern);
+
+                if elements.len() == 1 {
+                    self.output.push(',');
+                }
+
+                self.output.push(')');
+            },
+
+            PatternKind::TupleVariant(path, elements) => {
+                self.output.push_str(&path.join("::"));
+                self.output.push('(');
+                self.list(elements, Self::pattern);
+                self.output.push(')');
+            },
+
+            PatternKind::Struct(path, fields) => {
+                self.output.push_str(&path.join("::"));
+
+                if fields.is_empty() {
+                    self.output.push_str(" {}");
+                    return;
+                }
+
+                self.output.push_str(" { ");
+
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        self.output.push_str(", ");
+                    }
+
+                    self.output.push_str(&field.name);
+
+                    if !matches!(&ast[field.pattern].kind, PatternKind::Binding(name) if *name == field.name) {
+                        self.output.push_str(": ");
+                        self.pattern(field.pattern);
+                    }
+                }
+
+                self.output.push_str(" }");
+            }
+        }
+    }
+
+    pub fn ty(&mut self, id: TypeId) {
+        let ast = self.ast;
+
+        match &ast[id].kind {
+            TypeKind::Named(name) => self.output.push_str(name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::engine::DiagnosticEngine;
+    use crate::diagnostic::lint;
+    use crate::syntax::ast::Ast;
+    use crate::syntax::dump;
+    use crate::syntax::parser::Parser;
+    use crate::text::lexer::Lexer;
+    use std::fs;
+    use std::path::Path;
+
+    fn parse(path: &str, content: &str) -> Ast {
+        let mut engine = DiagnosticEngine::quiet(lint::LintLevels::default());
+        let tokens = Lexer::new(String::from(path), String::from(content)).tokenize(&mut engine);
+        let mut parser = Parser::new(&tokens);
+
+        assert!(parser.parse(&mut engine) && !engine.has_errors(), "{} does not parse:\n{}", path, content);
+        parser.ast
+    }
+
+    fn shape(ast: &Ast) -> Vec<(usize, String)> {
+        let mut output = Vec::new();
+        dump::text(ast, &mut output).unwrap();
+
+        let mut groups: Vec<usize> = Vec::new();
+        let mut lines = Vec::new();
+
+        for line in String::from_utf8(output).unwrap().lines() {
+            let label = &line[10..];
+            let depth = (label.len() - label.trim_start().len()) / 2;
+
+            while groups.last().is_some_and(|group| *group >= depth) {
+                groups.pop();
+            }
+
+            match label.trim_start() {
+                "Group" => groups.push(depth),
+                label => lines.push((depth - groups.len(), String::from(label)))
+            }
+        }
+
+        lines
+    }
+
+    fn round_trip(path: &str, content: &str) {
+        let original = parse(path, content);
+        let printed = super::source(&original);
+        let reparsed = parse(path, &printed);
+
+        assert_eq!(shape(&original), shape(&reparsed), "{} changed meaning when printed as:\n{}", path, printed);
+        assert_eq!(printed, super::source(&reparsed), "{} is not stable when printed twice", path);
+    }
+
+    #[test]
+    fn examples_round_trip() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        let mut count = 0;
+
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.extension().is_some_and(|extension| extension == "soy") {
+                round_trip(&path.to_string_lossy(), &fs::read_to_string(&path).unwrap());
+                count += 1;
+            }
+        }
+
+        assert!(count > 0);
+    }
+
+    #[test]
+    fn bare_return_round_trips() {
+        round_trip("return", "fn main() {\n    match x {\n        1 => return,\n        _ => f(return, (return)),\n    }\n}\n");
+        round_trip("return", "fn main() {\n    let a = (return) - 1;\n    (return).y;\n    (1.).x;\n    (1.)(2);\n    if (return) {}\n}\n");
+    }
+
+    struct Random(u64);
+
+    impl Random {
+        fn below(&mut self, bound: u64) -> u64 {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (self.0 >> 33) % bound
+        }
+    }
+
+    fn generate(random: &mut Random, depth: usize) -> String {
+        const BINARY: [&str; 18] = ["||", "&&", "==", "!=", "<", "<=", ">", ">=", "|", "^", "&", "<<", ">>", "+", "-", "*", "/", "%"];
+        const UNARY: [&str; 3] = ["-", "!", "~"];
+
+        if depth == 0 {
+            return match random.below(5) {
+                0 => random.below(100).to_string(),
+                1 => format!("{}.", random.below(100)),
+                2 => String::from("(return)"),
+                3 => String::from("x"),
+                _ => String::from("y")
+            };
+        }
+
+        match random.below(7) {
+            0 => format!("{}({})", UNARY[random.below(3) as usize], generate(random, depth - 1)),
+            1 => format!("f({}, {})", generate(random, depth - 1), generate(random, depth - 1)),
+            5 => format!("({})(x)", generate(random, depth - 1)),
+            2 => format!("({}).field", generate(random, depth - 1)),
+            3 => format!("(if {} {{ {} }} else {{ 0 }})", generate(random, depth - 1), generate(random, depth - 1)),
+
+            _ => format!(
+                "({} {} {})",
+                generate(random, depth - 1),
+                BINARY[random.below(18) as usize],
+                generate(random, depth - 1)
+            )
+        }
+    }
+
+    #[test]
+    fn random_expressions_round_trip() {
+        let mut random = Random(0x50f1e7);
+
+        for _ in 0..500 {
+            let expression = generate(&mut random, 4);
+            round_trip("generated", &format!("fn main() {{\n    let value = {};\n    {};\n}}\n", expression, expression));
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::syntax::cst::{SyntaxKind, SyntaxNode, SyntaxToken};
+use crate::text::token;
+
+pub trait View: Sized {
+    fn cast(node: SyntaxNode) -> Option<Self>;
+    fn syntax(&self) -> &SyntaxNode;
+}
+
+macro_rules! view {
+    ($name:ident, $($kind:ident)|+) => {
+        #[derive(Debug, Clone)]
+        pub struct $name(SyntaxNode);
+
+        impl View for $name {
+            fn cast(node: SyntaxNode) -> Option<Self> {
+                match node.kind() {
+                    $(SyntaxKind::$kind)|+ => Some(Self(node)),
+                    _ => None
+                }
+            }
+
+            fn syntax(&self) -> &SyntaxNode {
+                &self.0
+            }
+        }
+    };
+}
+
+view!(SourceFile, SourceFile);
+view!(Function, Function);
+view!(Parameter, Parameter);
+view!(Struct, Struct);
+view!(Enum, Enum);
+view!(Variant, Variant);
+view!(FieldDeclaration, FieldDeclaration);
+view!(Let, Let);
+view!(Block, Block);
+view!(Binary, Binary);
+view!(Call, Call);
+view!(Match, Match);
+view!(Arm, Arm);
+view!(Type, Type);
+
+view!(
+    Expression,
+    Literal | Identifier | Path | Tuple | Unary | Binary | Group | Call | FieldAccess | StructLiteral | Assign | Block | If | While | For | Break | Continue | Return | Match | Error
+);
+
+view!(
+    Pattern,
+    WildcardPattern | BindingPattern | LiteralPattern | TuplePattern | PathPattern | TupleVariantPattern | StructPattern
+);
+
+#[derive(Debug, Clone)]
+pub enum Item {
+    Function(Function),
+    Struct(Struct),
+    Enum(Enum)
+}
+
+fn child<V: View>(node: &SyntaxNode) -> Option<V> {
+    node.children().into_iter().find_map(V::cast)
+}
+
+fn children<V: View>(node: &SyntaxNode) -> Vec<V> {
+    node.children().into_iter().filter_map(V::cast).collect()
+}
+
+fn name(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.tokens().into_iter().find(|token| *token.kind() == token::Kind::Identifier && !["fn", "struct", "enum", "let"].contains(&token.text()))
+}
+
+impl Item {
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Function => Some(Item::Function(Function(node))),
+            SyntaxKind::Struct => Some(Item::Struct(Struct(node))),
+            SyntaxKind::Enum => Some(Item::Enum(Enum(node))),
+            _ => None
+        }
+    }
+}
+
+impl SourceFile {
+    pub fn items(&self) -> Vec<Item> {
+        self.0.children().into_iter().filter_map(Item::cast).collect()
+    }
+}
+
+impl Function {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        name(&self.0)
+    }
+
+    pub fn parameters(&self) -> Vec<Parameter> {
+        children(&self.0)
+    }
+
+    pub fn output(&self) -> Option<Type> {
+        child(&self.0)
+    }
+
+    pub fn body(&self) -> Option<Block> {
+        child(&self.0)
+    }
+}
+
+impl Parameter {
+    pub fn pattern(&self) -> Option<Pattern> {
+        child(&self.0)
+    }
+
+    pub fn ty(&self) -> Option<Type> {
+        child(&self.0)
+    }
+}
+
+impl Struct {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        name(&self.0)
+    }
+
+    pub fn fields(&self) -> Vec<FieldDeclaration> {
+        children(&self.0)
+    }
+}
+
+impl Enum {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        name(&self.0)
+    }
+
+    pub fn variants(&self) -> Vec<Variant> {
+        children(&self.0)
+    }
+}
+
+impl Variant {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        name(&self.0)
+    }
+
+    pub fn fields(&self) -> Vec<FieldDeclaration> {
+        children(&self.0)
+    }
+
+    pub fn types(&self) -> Vec<Type> {
+        children(&self.0)
+    }
+}
+
+impl FieldDeclaration {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        name(&self.0)
+    }
+
+    pub fn ty(&self) -> Option<Type> {
+        child(&self.0)
+    }
+}
+
+impl Let {
+    pub fn pattern(&self) -> Option<Pattern> {
+        child(&self.0)
+    }
+
+    pub fn ty(&self) -> Option<Type> {
+        child(&self.0)
+    }
+
+    pub fn value(&self) -> Option<Expression> {
+        child(&self.0)
+    }
+}
+
+impl Block {
+    pub fn statements(&self) -> Vec<SyntaxNode> {
+        self.0.children()
+    }
+}
+
+impl Binary {
+    pub fn left(&self) -> Option<Expression> {
+        child(&self.0)
+    }
+
+    pub fn operator(&self) -> Option<SyntaxToken> {
+        self.0.tokens().into_iter().next()
+    }
+
+    pub fn right(&self) -> Option<Expression> {
+        children(&self.0).into_iter().nth(1)
+    }
+}
+
+impl Call {
+    pub fn callee(&self) -> Option<Expression> {
+        child(&self.0)
+    }
+
+    pub fn arguments(&self) -> Vec<Expression> {
+        children(&self.0).into_iter().skip(1).collect()
+    }
+}
+
+impl Match {
+    pub fn scrutinee(&self) -> Option<Expression> {
+        child(&self.0)
+    }
+
+    pub fn arms(&self) -> Vec<Arm> {
+        children(&self.0)
+    }
+}
+
+impl Arm {
+    pub fn pattern(&self) -> Option<Pattern> {
+        child(&self.0)
+    }
+
+    pub fn guard(&self) -> Option<Expression> {
+        let expressions = children::<Expression>(&self.0);
+
+        match expressions.len() {
+            2 => expressions.into_iter().next(),
+            _ => None
+        }
+    }
+
+    pub fn body(&self) -> Option<Expression> {
+        children(&self.0).into_iter().last()
+    }
+}
+
+impl Type {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        name(&self.0)
+    }
+}
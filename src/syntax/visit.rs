@@ -0,0 +1,951 @@
+use crate::syntax::ast::{Arm, Ast, ExpressionId, ExpressionKind, Field, FieldPattern, FieldValue, ItemId, ItemKind, Parameter, PatternId, PatternKind, StatementId, StatementKind, TypeId, Variant, VariantData};
+
+pub trait Visitor<'a>: Sized {
+    fn visit_item(&mut self, ast: &'a Ast, id: ItemId) {
+        walk_item(self, ast, id)
+    }
+
+    fn visit_parameter(&mut self, ast: &'a Ast, parameter: &'a Parameter) {
+        walk_parameter(self, ast, parameter)
+    }
+
+    fn visit_field(&mut self, ast: &'a Ast, field: &'a Field) {
+        walk_field(self, ast, field)
+    }
+
+    fn visit_variant(&mut self, ast: &'a Ast, variant: &'a Variant) {
+        walk_variant(self, ast, variant)
+    }
+
+    fn visit_statement(&mut self, ast: &'a Ast, id: StatementId) {
+        walk_statement(self, ast, id)
+    }
+
+    fn visit_expression(&mut self, ast: &'a Ast, id: ExpressionId) {
+        walk_expression(self, ast, id)
+    }
+
+    fn visit_field_value(&mut self, ast: &'a Ast, field: &'a FieldValue) {
+        walk_field_value(self, ast, field)
+    }
+
+    fn visit_arm(&mut self, ast: &'a Ast, arm: &'a Arm) {
+        walk_arm(self, ast, arm)
+    }
+
+    fn visit_pattern(&mut self, ast: &'a Ast, id: PatternId) {
+        walk_pattern(self, ast, id)
+    }
+
+    fn visit_field_pattern(&mut self, ast: &'a Ast, field: &'a FieldPattern) {
+        walk_field_pattern(self, ast, field)
+    }
+
+    fn visit_ty(&mut self, _ast: &'a Ast, _id: TypeId) {}
+}
+
+pub trait VisitorMut: Sized {
+    fn visit_item(&mut self, ast: &mut Ast, id: ItemId) {
+        walk_item_mut(self, ast, id)
+    }
+
+    fn visit_parameter(&mut self, ast: &mut Ast, parameter: &mut Parameter) {
+        walk_parameter_mut(self, ast, parameter)
+    }
+
+    fn visit_field(&mut self, ast: &mut Ast, field: &mut Field) {
+        walk_field_mut(self, ast, field)
+    }
+
+    fn visit_variant(&mut self, ast: &mut Ast, variant: &mut Variant) {
+        walk_variant_mut(self, ast, variant)
+    }
+
+    fn visit_statement(&mut self, ast: &mut Ast, id: StatementId) {
+        walk_statement_mut(self, ast, id)
+    }
+
+    fn visit_expression(&mut self, ast: &mut Ast, id: ExpressionId) {
+        walk_expression_mut(self, ast, id)
+    }
+
+    fn visit_field_value(&mut self, ast: &mut Ast, field: &mut FieldValue) {
+        walk_field_value_mut(self, ast, field)
+    }
+
+    fn visit_arm(&mut self, ast: &mut Ast, arm: &mut Arm) {
+        walk_arm_mut(self, ast, arm)
+    }
+
+    fn visit_pattern(&mut self, ast: &mut Ast, id: PatternId) {
+        walk_pattern_mut(self, ast, id)
+    }
+
+    fn visit_field_pattern(&mut self, ast: &mut Ast, field: &mut FieldPattern) {
+        walk_field_pattern_mut(self, ast, field)
+    }
+
+    fn visit_ty(&mut self, _ast: &mut Ast, _id: TypeId) {}
+}
+
+pub trait Fold: Sized {
+    fn fold_item(&mut self, ast: &mut Ast, id: ItemId) -> ItemId {
+        fold_item(self, ast, id)
+    }
+
+    fn fold_parameter(&mut self, ast: &mut Ast, parameter: Parameter) -> Parameter {
+        fold_parameter(self, ast, parameter)
+    }
+
+    fn fold_field(&mut self, ast: &mut Ast, field: Field) -> Field {
+        fold_field(self, ast, field)
+    }
+
+    fn fold_variant(&mut self, ast: &mut Ast, variant: Variant) -> Variant {
+        fold_variant(self, ast, variant)
+    }
+
+    fn fold_statement(&mut self, ast: &mut Ast, id: StatementId) -> StatementId {
+        fold_statement(self, ast, id)
+    }
+
+    fn fold_expression(&mut self, ast: &mut Ast, id: ExpressionId) -> ExpressionId {
+        fold_expression(self, ast, id)
+    }
+
+    fn fold_field_value(&mut self, ast: &mut Ast, field: FieldValue) -> FieldValue {
+        fold_field_value(self, ast, field)
+    }
+
+    fn fold_arm(&mut self, ast: &mut Ast, arm: Arm) -> Arm {
+        fold_arm(self, ast, arm)
+    }
+
+    fn fold_pattern(&mut self, ast: &mut Ast, id: PatternId) -> PatternId {
+        fold_pattern(self, ast, id)
+    }
+
+    fn fold_field_pattern(&mut self, ast: &mut Ast, field: FieldPattern) -> FieldPattern {
+        fold_field_pattern(self, ast, field)
+    }
+
+    fn fold_ty(&mut self, _ast: &mut Ast, id: TypeId) -> TypeId {
+        id
+    }
+}
+
+pub fn walk_ast<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast) {
+    for root in &ast.roots {
+        visitor.visit_item(ast, *root);
+    }
+}
+
+pub fn walk_item<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, id: ItemId) {
+    match &ast[id].kind {
+        ItemKind::Function(function) => {
+            for parameter in &function.parameters {
+                visitor.visit_parameter(ast, parameter);
+            }
+
+            if let Some(output) = function.output {
+                visitor.visit_ty(ast, output);
+            }
+
+            visitor.visit_expression(ast, function.body);
+        },
+
+        ItemKind::Struct(item) => {
+            for field in &item.fields {
+                visitor.visit_field(ast, field);
+            }
+        },
+
+        ItemKind::Enum(item) => {
+            for variant in &item.variants {
+                visitor.visit_variant(ast, variant);
+            }
+        },
+
+        ItemKind::Error => ()
+    }
+}
+
+pub fn walk_parameter<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, parameter: &'a Parameter) {
+    visitor.visit_pattern(ast, parameter.pattern);
+    visitor.visit_ty(ast, parameter.ty);
+}
+
+pub fn walk_field<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, field: &'a Field) {
+    visitor.visit_ty(ast, field.ty);
+}
+
+pub fn walk_variant<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, variant: &'a Variant) {
+    match &variant.data {
+        VariantData::Unit => (),
+
+        VariantData::Tuple(types) => {
+            for ty in types {
+                visitor.visit_ty(ast, *ty);
+            }
+        },
+
+        VariantData::Struct(fields) => {
+            for field in fields {
+                visitor.visit_field(ast, field);
+            }
+        }
+    }
+}
+
+pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, id: StatementId) {
+    match &ast[id].kind {
+        StatementKind::Let(pattern, annotation, value) => {
+            visitor.visit_pattern(ast, *pattern);
+
+            if let Some(annotation) = annotation {
+                visitor.visit_ty(ast, *annotation);
+            }
+
+            if let Some(value) = value {
+                visitor.visit_expression(ast, *value);
+            }
+        },
+
+        StatementKind::Expression(value) => visitor.visit_expression(ast, *value),
+        StatementKind::Item(item) => visitor.visit_item(ast, *item)
+    }
+}
+
+pub fn walk_expression<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, id: ExpressionId) {
+    match &ast[id].kind {
+        ExpressionKind::Tuple(elements) => {
+            for element in elements {
+                visitor.visit_expression(ast, *element);
+            }
+        },
+
+        ExpressionKind::Unary(_, operand) | ExpressionKind::Group(operand) | ExpressionKind::Field(operand, _) => visitor.visit_expression(ast, *operand),
+
+        ExpressionKind::Binary(left, _, right) | ExpressionKind::Assign(left, _, right) | ExpressionKind::While(left, right) => {
+            visitor.visit_expression(ast, *left);
+            visitor.visit_expression(ast, *right);
+        },
+
+        ExpressionKind::Call(callee, arguments) => {
+            visitor.visit_expression(ast, *callee);
+
+            for argument in arguments {
+                visitor.visit_expression(ast, *argument);
+            }
+        },
+
+        ExpressionKind::Struct(_, fields) => {
+            for field in fields {
+                visitor.visit_field_value(ast, field);
+            }
+        },
+
+        ExpressionKind::Block(block) => {
+            for statement in &block.statements {
+                visitor.visit_statement(ast, *statement);
+            }
+
+            if let Some(tail) = block.tail {
+                visitor.visit_expression(ast, tail);
+            }
+        },
+
+        ExpressionKind::If(condition, then, otherwise) => {
+            visitor.visit_expression(ast, *condition);
+            visitor.visit_expression(ast, *then);
+
+            if let Some(otherwise) = otherwise {
+                visitor.visit_expression(ast, *otherwise);
+            }
+        },
+
+        ExpressionKind::For(binding, iterable, body) => {
+            visitor.visit_pattern(ast, *binding);
+            visitor.visit_expression(ast, *iterable);
+            visitor.visit_expression(ast, *body);
+        },
+
+        ExpressionKind::Return(Some(value)) => visitor.visit_expression(ast, *value),
+
+        ExpressionKind::Match(scrutinee, arms) => {
+            visitor.visit_expression(ast, *scrutinee);
+
+            for arm in arms {
+                visitor.visit_arm(ast, arm);
+            }
+        },
+
+        _ => ()
+    }
+}
+
+pub fn walk_field_value<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, field: &'a FieldValue) {
+    visitor.visit_expression(ast, field.value);
+}
+
+pub fn walk_arm<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, arm: &'a Arm) {
+    visitor.visit_pattern(ast, arm.pattern);
+
+    if let Some(guard) = arm.guard {
+        visitor.visit_expression(ast, guard);
+    }
+
+    visitor.visit_expression(ast, arm.body);
+}
+
+pub fn walk_pattern<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, id: PatternId) {
+    match &ast[id].kind {
+        PatternKind::Literal(value) => visitor.visit_expression(ast, *value),
+
+        PatternKind::Tuple(elements) | PatternKind::TupleVariant(_, elements) => {
+            for element in elements {
+                visitor.visit_pattern(ast, *element);
+            }
+        },
+
+        PatternKind::Struct(_, fields) => {
+            for field in fields {
+                visitor.visit_field_pattern(ast, field);
+            }
+        },
+
+        _ => ()
+    }
+}
+
+pub fn walk_field_pattern<'a, V: Visitor<'a>>(visitor: &mut V, ast: &'a Ast, field: &'a FieldPattern) {
+    visitor.visit_pattern(ast, field.pattern);
+}
+
+pub fn walk_ast_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast) {
+    for root in ast.roots.clone() {
+        visitor.visit_item(ast, root);
+    }
+}
+
+pub fn walk_item_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, id: ItemId) {
+    match ast[id].kind.clone() {
+        ItemKind::Function(mut function) => {
+            for parameter in &mut function.parameters {
+                visitor.visit_parameter(ast, parameter);
+            }
+
+            if let Some(output) = function.output {
+                visitor.visit_ty(ast, output);
+            }
+
+            visitor.visit_expression(ast, function.body);
+
+            if let ItemKind::Function(current) = &mut ast[id].kind {
+                current.parameters = function.parameters;
+            }
+        },
+
+        ItemKind::Struct(mut item) => {
+            for field in &mut item.fields {
+                visitor.visit_field(ast, field);
+            }
+
+            if let ItemKind::Struct(current) = &mut ast[id].kind {
+                current.fields = item.fields;
+            }
+        },
+
+        ItemKind::Enum(mut item) => {
+            for variant in &mut item.variants {
+                visitor.visit_variant(ast, variant);
+            }
+
+            if let ItemKind::Enum(current) = &mut ast[id].kind {
+                current.variants = item.variants;
+            }
+        },
+
+        ItemKind::Error => ()
+    }
+}
+
+pub fn walk_parameter_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, parameter: &mut Parameter) {
+    visitor.visit_pattern(ast, parameter.pattern);
+    visitor.visit_ty(ast, parameter.ty);
+}
+
+pub fn walk_field_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, field: &mut Field) {
+    visitor.visit_ty(ast, field.ty);
+}
+
+pub fn walk_variant_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, variant: &mut Variant) {
+    match &mut variant.data {
+        VariantData::Unit => (),
+
+        VariantData::Tuple(types) => {
+            for ty in types {
+                visitor.visit_ty(ast, *ty);
+            }
+        },
+
+        VariantData::Struct(fields) => {
+            for field in fields {
+                visitor.visit_field(ast, field);
+            }
+        }
+    }
+}
+
+pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, id: StatementId) {
+    match ast[id].kind.clone() {
+        StatementKind::Let(pattern, annotation, value) => {
+            visitor.visit_pattern(ast, pattern);
+
+            if let Some(annotation) = annotation {
+                visitor.visit_ty(ast, annotation);
+            }
+
+            if let Some(value) = value {
+                visitor.visit_expression(ast, value);
+            }
+        },
+
+        StatementKind::Expression(value) => visitor.visit_expression(ast, value),
+        StatementKind::Item(item) => visitor.visit_item(ast, item)
+    }
+}
+
+pub fn walk_expression_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, id: ExpressionId) {
+    match ast[id].kind.clone() {
+        ExpressionKind::Tuple(elements) => {
+            for element in elements {
+                visitor.visit_expression(ast, element);
+            }
+        },
+
+        ExpressionKind::Unary(_, operand) | ExpressionKind::Group(operand) | ExpressionKind::Field(operand, _) => visitor.visit_expression(ast, operand),
+
+        ExpressionKind::Binary(left, _, right) | ExpressionKind::Assign(left, _, right) | ExpressionKind::While(left, right) => {
+            visitor.visit_expression(ast, left);
+            visitor.visit_expression(ast, right);
+        },
+
+        ExpressionKind::Call(callee, arguments) => {
+            visitor.visit_expression(ast, callee);
+
+            for argument in arguments {
+                visitor.visit_expression(ast, argument);
+            }
+        },
+
+        ExpressionKind::Struct(_, mut fields) => {
+            for field in &mut fields {
+                visitor.visit_field_value(ast, field);
+            }
+
+            if let ExpressionKind::Struct(_, current) = &mut ast[id].kind {
+                *current = fields;
+            }
+        },
+
+        ExpressionKind::Block(block) => {
+            for statement in block.statements {
+                visitor.visit_statement(ast, statement);
+            }
+
+            if let Some(tail) = block.tail {
+                visitor.visit_expression(ast, tail);
+            }
+        },
+
+        ExpressionKind::If(condition, then, otherwise) => {
+            visitor.visit_expression(ast, condition);
+            visitor.visit_expression(ast, then);
+
+            if let Some(otherwise) = otherwise {
+                visitor.visit_expression(ast, otherwise);
+            }
+        },
+
+        ExpressionKind::For(binding, iterable, body) => {
+            visitor.visit_pattern(ast, binding);
+            visitor.visit_expression(ast, iterable);
+            visitor.visit_expression(ast, body);
+        },
+
+        ExpressionKind::Return(Some(value)) => visitor.visit_expression(ast, value),
+
+        ExpressionKind::Match(scrutinee, mut arms) => {
+            visitor.visit_expression(ast, scrutinee);
+
+            for arm in &mut arms {
+                visitor.visit_arm(ast, arm);
+            }
+
+            if let ExpressionKind::Match(_, current) = &mut ast[id].kind {
+                *current = arms;
+            }
+        },
+
+        _ => ()
+    }
+}
+
+pub fn walk_field_value_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, field: &mut FieldValue) {
+    visitor.visit_expression(ast, field.value);
+}
+
+pub fn walk_arm_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, arm: &mut Arm) {
+    visitor.visit_pattern(ast, arm.pattern);
+
+    if let Some(guard) = arm.guard {
+        visitor.visit_expression(ast, guard);
+    }
+
+    visitor.visit_expression(ast, arm.body);
+}
+
+pub fn walk_pattern_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, id: PatternId) {
+    match ast[id].kind.clone() {
+        PatternKind::Literal(value) => visitor.visit_expression(ast, value),
+
+        PatternKind::Tuple(elements) | PatternKind::TupleVariant(_, elements) => {
+            for element in elements {
+                visitor.visit_pattern(ast, element);
+            }
+        },
+
+        PatternKind::Struct(_, mut fields) => {
+            for field in &mut fields {
+                visitor.visit_field_pattern(ast, field);
+            }
+
+            if let PatternKind::Struct(_, current) = &mut ast[id].kind {
+                *current = fields;
+            }
+        },
+
+        _ => ()
+    }
+}
+
+pub fn walk_field_pattern_mut<V: VisitorMut>(visitor: &mut V, ast: &mut Ast, field: &mut FieldPattern) {
+    visitor.visit_pattern(ast, field.pattern);
+}
+
+pub fn fold_ast<F: Fold>(folder: &mut F, ast: &mut Ast) {
+    for index in 0..ast.roots.len() {
+        ast.roots[index] = folder.fold_item(ast, ast.roots[index]);
+    }
+}
+
+pub fn fold_item<F: Fold>(folder: &mut F, ast: &mut Ast, id: ItemId) -> ItemId {
+    let kind = match ast[id].kind.clone() {
+        ItemKind::Function(mut function) => {
+            function.parameters = function.parameters.into_iter().map(|parameter| folder.fold_parameter(ast, parameter)).collect();
+            function.output = function.output.map(|output| folder.fold_ty(ast, output));
+            function.body = folder.fold_expression(ast, function.body);
+
+            ItemKind::Function(function)
+        },
+
+        ItemKind::Struct(mut item) => {
+            item.fields = item.fields.into_iter().map(|field| folder.fold_field(ast, field)).collect();
+            ItemKind::Struct(item)
+        },
+
+        ItemKind::Enum(mut item) => {
+            item.variants = item.variants.into_iter().map(|variant| folder.fold_variant(ast, variant)).collect();
+            ItemKind::Enum(item)
+        },
+
+        ItemKind::Error => ItemKind::Error
+    };
+
+    ast[id].kind = kind;
+    id
+}
+
+pub fn fold_parameter<F: Fold>(folder: &mut F, ast: &mut Ast, mut parameter: Parameter) -> Parameter {
+    parameter.pattern = folder.fold_pattern(ast, parameter.pattern);
+    parameter.ty = folder.fold_ty(ast, parameter.ty);
+    parameter
+}
+
+pub fn fold_field<F: Fold>(folder: &mut F, ast: &mut Ast, mut field: Field) -> Field {
+    field.ty = folder.fold_ty(ast, field.ty);
+    field
+}
+
+pub fn fold_variant<F: Fold>(folder: &mut F, ast: &mut Ast, mut variant: Variant) -> Variant {
+    variant.data = match variant.data {
+        VariantData::Unit => VariantData::Unit,
+        VariantData::Tuple(types) => VariantData::Tuple(types.into_iter().map(|ty| folder.fold_ty(ast, ty)).collect()),
+        VariantData::Struct(fields) => VariantData::Struct(fields.into_iter().map(|field| folder.fold_field(ast, field)).collect())
+    };
+
+    variant
+}
+
+pub fn fold_statement<F: Fold>(folder: &mut F, ast: &mut Ast, id: StatementId) -> StatementId {
+    let kind = match ast[id].kind.clone() {
+        StatementKind::Let(pattern, annotation, value) => StatementKind::Let(
+            folder.fold_pattern(ast, pattern),
+            annotation.map(|annotation| folder.fold_ty(ast, annotation)),
+            value.map(|value| folder.fold_expression(ast, value))
+        ),
+
+        StatementKind::Expression(value) => StatementKind::Expression(folder.fold_expression(ast, value)),
+        StatementKind::Item(item) => StatementKind::Item(folder.fold_item(ast, item))
+    };
+
+    ast[id].kind = kind;
+    id
+}
+
+pub fn fold_expression<F: Fold>(folder: &mut F, ast: &mut Ast, id: ExpressionId) -> ExpressionId {
+    let kind = match ast[id].kind.clone() {
+        ExpressionKind::Tuple(elements) => {
+            ExpressionKind::Tuple(elements.into_iter().map(|element| folder.fold_expression(ast, element)).collect())
+        },
+
+        ExpressionKind::Unary(operator, operand) => ExpressionKind::Unary(operator, folder.fold_expression(ast, operand)),
+
+        ExpressionKind::Binary(left, operator, right) => {
+            let left = folder.fold_expression(ast, left);
+            ExpressionKind::Binary(left, operator, folder.fold_expression(ast, right))
+        },
+
+        ExpressionKind::Group(inner) => ExpressionKind::Group(folder.fold_expression(ast, inner)),
+
+        ExpressionKind::Call(callee, arguments) => {
+            let callee = folder.fold_expression(ast, callee);
+            ExpressionKind::Call(callee, arguments.into_iter().map(|argument| folder.fold_expression(ast, argument)).collect())
+        },
+
+        ExpressionKind::Field(target, name) => ExpressionKind::Field(folder.fold_expression(ast, target), name),
+
+        ExpressionKind::Struct(path, fields) => {
+            ExpressionKind::Struct(path, fields.into_iter().map(|field| folder.fold_field_value(ast, field)).collect())
+        },
+
+        ExpressionKind::Assign(target, operator, value) => {
+            let target = folder.fold_expression(ast, target);
+            ExpressionKind::Assign(target, operator, folder.fold_expression(ast, value))
+        },
+
+        ExpressionKind::Block(mut block) => {
+            block.statements = block.statements.into_iter().map(|statement| folder.fold_statement(ast, statement)).collect();
+            block.tail = block.tail.map(|tail| folder.fold_expression(ast, tail));
+
+            ExpressionKind::Block(block)
+        },
+
+        ExpressionKind::If(condition, then, otherwise) => {
+            let condition = folder.fold_expression(ast, condition);
+            let then = folder.fold_expression(ast, then);
+
+            ExpressionKind::If(condition, then, otherwise.map(|otherwise| folder.fold_expression(ast, otherwise)))
+        },
+
+        ExpressionKind::While(condition, body) => {
+            let condition = folder.fold_expression(ast, condition);
+            ExpressionKind::While(condition, folder.fold_expression(ast, body))
+        },
+
+        ExpressionKind::For(binding, iterable, body) => {
+            let binding = folder.fold_pattern(ast, binding);
+            let iterable = folder.fold_expression(ast, iterable);
+
+            ExpressionKind::For(binding, iterable, folder.fold_expression(ast, body))
+        },
+
+        ExpressionKind::Return(value) => ExpressionKind::Return(value.map(|value| folder.fold_expression(ast, value))),
+
+        ExpressionKind::Match(scrutinee, arms) => {
+            let scrutinee = folder.fold_expression(ast, scrutinee);
+            ExpressionKind::Match(scrutinee, arms.into_iter().map(|arm| folder.fold_arm(ast, arm)).collect())
+        },
+
+        kind => kind
+    };
+
+    ast[id].kind = kind;
+    id
+}
+
+pub fn fold_field_value<F: Fold>(folder: &mut F, ast: &mut Ast, mut field: FieldValue) -> FieldValue {
+    field.value = folder.fold_expression(ast, field.value);
+    field
+}
+
+pub fn fold_arm<F: Fold>(folder: &mut F, ast: &mut Ast, mut arm: Arm) -> Arm {
+    arm.pattern = folder.fold_pattern(ast, arm.pattern);
+    arm.guard = arm.guard.map(|guard| folder.fold_expression(ast, guard));
+    arm.body = folder.fold_expression(ast, arm.body);
+    arm
+}
+
+pub fn fold_pattern<F: Fold>(folder: &mut F, ast: &mut Ast, id: PatternId) -> PatternId {
+    let kind = match ast[id].kind.clone() {
+        PatternKind::Literal(value) => PatternKind::Literal(folder.fold_expression(ast, value)),
+        PatternKind::Tuple(elements) => PatternKind::Tuple(elements.into_iter().map(|element| folder.fold_pattern(ast, element)).collect()),
+
+        PatternKind::TupleVariant(path, elements) => {
+            PatternKind::TupleVariant(path, elements.into_iter().map(|element| folder.fold_pattern(ast, element)).collect())
+        },
+
+        PatternKind::Struct(path, fields) => {
+            PatternKind::Struct(path, fields.into_iter().map(|field| folder.fold_field_pattern(ast, field)).collect())
+        },
+
+        kind => kind
+    };
+
+    ast[id].kind = kind;
+    id
+}
+
+pub fn fold_field_pattern<F: Fold>(folder: &mut F, ast: &mut Ast, mut field: FieldPattern) -> FieldPattern {
+    field.pattern = folder.fold_pattern(ast, field.pattern);
+    field
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fold, Visitor, VisitorMut};
+    use crate::diagnostic::engine::DiagnosticEngine;
+    use crate::diagnostic::lint;
+    use crate::syntax::ast::{Arm, Ast, BinaryOperator, ExpressionId, ExpressionKind, Field, FieldPattern, FieldValue, ItemKind, Parameter, PatternId, PatternKind, Variant};
+    use crate::syntax::count;
+    use crate::syntax::parser::Parser;
+    use crate::syntax::printer;
+    use crate::text::lexer::Lexer;
+    use std::fs;
+    use std::path::Path;
+
+    fn parse(content: &str) -> Ast {
+        let mut engine = DiagnosticEngine::quiet(lint::LintLevels::default());
+        let tokens = Lexer::new(String::from("test"), String::from(content)).tokenize(&mut engine);
+        let mut parser = Parser::new(&tokens);
+
+        assert!(parser.parse(&mut engine), "does not parse:\n{}", content);
+        parser.ast
+    }
+
+    #[test]
+    fn counter_reaches_every_node() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+
+        for entry in fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+
+            if path.extension().is_some_and(|extension| extension == "soy") {
+                let ast = parse(&fs::read_to_string(&path).unwrap());
+                let counter = count::nodes(&ast);
+
+                assert_eq!(counter.total(), ast.len(), "{}", path.display());
+                assert_eq!(counter.expressions, ast.expressions.len(), "{}", path.display());
+                assert_eq!(counter.patterns, ast.patterns.len(), "{}", path.display());
+            }
+        }
+    }
+
+    #[test]
+    fn counter_counts_each_kind() {
+        let ast = parse("struct P { x: int }\n\nfn main() -> int {\n    let (a, _) = (1, 2);\n    match a { 0 => a, n => -n }\n}\n");
+
+        assert_eq!(
+            count::nodes(&ast),
+            count::Counter {
+                items: 2,
+                statements: 1,
+                expressions: 10,
+                patterns: 5,
+                types: 2
+            }
+        );
+    }
+
+    struct Bindings<'a>(Vec<&'a str>);
+
+    impl<'a> Visitor<'a> for Bindings<'a> {
+        fn visit_pattern(&mut self, ast: &'a Ast, id: PatternId) {
+            if let PatternKind::Binding(name) = &ast[id].kind {
+                self.0.push(name);
+            }
+
+            super::walk_pattern(self, ast, id)
+        }
+    }
+
+    #[test]
+    fn visitor_walks_in_source_order() {
+        let ast = parse("fn f(a: int) {\n    let b = 1;\n    for c in b {}\n    match a { (d, e) if d => e, S { f, g: h } => h }\n}\n");
+        let mut bindings = Bindings(Vec::new());
+
+        super::walk_ast(&mut bindings, &ast);
+
+        assert_eq!(bindings.0, ["a", "b", "c", "d", "e", "f", "h"]);
+    }
+
+    struct Rename;
+
+    impl VisitorMut for Rename {
+        fn visit_expression(&mut self, ast: &mut Ast, id: ExpressionId) {
+            if let ExpressionKind::Identifier(name) = &mut ast[id].kind {
+                name.insert(0, '_');
+            }
+
+            super::walk_expression_mut(self, ast, id)
+        }
+
+        fn visit_pattern(&mut self, ast: &mut Ast, id: PatternId) {
+            if let PatternKind::Binding(name) = &mut ast[id].kind {
+                name.insert(0, '_');
+            }
+
+            super::walk_pattern_mut(self, ast, id)
+        }
+    }
+
+    #[test]
+    fn visitor_mut_edits_in_place() {
+        let mut ast = parse("fn main() {\n    let x = 1;\n    f(x + y);\n}\n");
+
+        super::walk_ast_mut(&mut Rename, &mut ast);
+
+        assert_eq!(printer::source(&ast), "fn main() {\n    let _x = 1;\n    _f(_x + _y);\n}\n");
+    }
+
+    struct Desugar;
+
+    impl Fold for Desugar {
+        fn fold_expression(&mut self, ast: &mut Ast, id: ExpressionId) -> ExpressionId {
+            let id = super::fold_expression(self, ast, id);
+
+            let ExpressionKind::Assign(target, Some(operator), value) = ast[id].kind else {
+                return id;
+            };
+
+            let span = ast[id].span.clone();
+            let place = ast.expression(ast[target].kind.clone(), ast[target].span.clone());
+            let value = ast.expression(ExpressionKind::Binary(place, operator, value), span.clone());
+
+            ast.expression(ExpressionKind::Assign(target, None, value), span)
+        }
+    }
+
+    #[test]
+    fn fold_replaces_nodes() {
+        let mut ast = parse("fn main() {\n    x += 1;\n    y.z *= x -= 2;\n}\n");
+
+        super::fold_ast(&mut Desugar, &mut ast);
+
+        assert_eq!(printer::source(&ast), "fn main() {\n    x = x + 1;\n    y.z = y.z * (x = x - 2);\n}\n");
+        assert!(matches!(ast.expressions[ast.expressions.len() - 2].kind, ExpressionKind::Binary(_, BinaryOperator::Multiply, _)));
+    }
+
+    struct Enclosing(Vec<bool>);
+
+    impl Enclosing {
+        fn check(&mut self, ast: &Ast) {
+            let body = ast.main().and_then(|main| match &ast[main].kind {
+                ItemKind::Function(function) => Some(function.body),
+                _ => None
+            });
+
+            self.0.push(body.is_some_and(|body| matches!(ast[body].kind, ExpressionKind::Block(_))));
+        }
+    }
+
+    impl VisitorMut for Enclosing {
+        fn visit_expression(&mut self, ast: &mut Ast, id: ExpressionId) {
+            self.check(ast);
+            super::walk_expression_mut(self, ast, id)
+        }
+    }
+
+    impl Fold for Enclosing {
+        fn fold_expression(&mut self, ast: &mut Ast, id: ExpressionId) -> ExpressionId {
+            self.check(ast);
+            super::fold_expression(self, ast, id)
+        }
+    }
+
+    #[test]
+    fn passes_see_enclosing_nodes() {
+        let mut ast = parse("fn main() {\n    match (1, 2) { (a, _) if a > 0 => a, _ => 0 }\n}\n");
+
+        let mut visitor = Enclosing(Vec::new());
+        super::walk_ast_mut(&mut visitor, &mut ast);
+        assert!(visitor.0.len() > 5 && visitor.0.iter().all(|seen| *seen));
+
+        let mut folder = Enclosing(Vec::new());
+        super::fold_ast(&mut folder, &mut ast);
+        assert!(folder.0.len() > 5 && folder.0.iter().all(|seen| *seen));
+    }
+
+    struct Shout;
+
+    impl VisitorMut for Shout {
+        fn visit_parameter(&mut self, ast: &mut Ast, parameter: &mut Parameter) {
+            if let PatternKind::Binding(name) = &mut ast[parameter.pattern].kind {
+                *name = name.to_uppercase();
+            }
+
+            super::walk_parameter_mut(self, ast, parameter)
+        }
+
+        fn visit_field(&mut self, ast: &mut Ast, field: &mut Field) {
+            field.name = field.name.to_uppercase();
+            super::walk_field_mut(self, ast, field)
+        }
+
+        fn visit_variant(&mut self, ast: &mut Ast, variant: &mut Variant) {
+            variant.name = variant.name.to_uppercase();
+            super::walk_variant_mut(self, ast, variant)
+        }
+
+        fn visit_field_value(&mut self, ast: &mut Ast, field: &mut FieldValue) {
+            field.name = field.name.to_uppercase();
+            super::walk_field_value_mut(self, ast, field)
+        }
+
+        fn visit_field_pattern(&mut self, ast: &mut Ast, field: &mut FieldPattern) {
+            field.name = field.name.to_uppercase();
+            super::walk_field_pattern_mut(self, ast, field)
+        }
+    }
+
+    #[test]
+    fn visitor_mut_reaches_inner_structures() {
+        let mut ast = parse("struct P { x: int }\n\nenum E { v { y: int } }\n\nfn f(a: int) {\n    match (P { x: a }) { P { x: b } => b }\n}\n");
+
+        super::walk_ast_mut(&mut Shout, &mut ast);
+
+        assert_eq!(
+            printer::source(&ast),
+            "struct P {\n    X: int\n}\n\nenum E {\n    V {\n        Y: int\n    }\n}\n\nfn f(A: int) {\n    match (P { X: a }) {\n        P { X: b } => b,\n    }\n}\n"
+        );
+    }
+
+    struct Unguard;
+
+    impl Fold for Unguard {
+        fn fold_arm(&mut self, ast: &mut Ast, mut arm: Arm) -> Arm {
+            arm.guard = None;
+            super::fold_arm(self, ast, arm)
+        }
+    }
+
+    #[test]
+    fn fold_replaces_inner_structures() {
+        let mut ast = parse("fn main() {\n    match x { 1 if y => 2, _ => 3 }\n}\n");
+
+        super::fold_ast(&mut Unguard, &mut ast);
+
+        assert_eq!(printer::source(&ast), "fn main() {\n    match x {\n        1 => 2,\n        _ => 3,\n    }\n}\n");
+    }
+}
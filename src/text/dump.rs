@@ -0,0 +1,35 @@
+use crate::text::token;
+use crate::utils::json;
+use std::io::{Result, Write};
+
+pub fn text(tokens: &[token::Token], output: &mut impl Write) -> Result<()> {
+    for token in tokens {
+        let position = format!("{}:{}", token.span.row, token.span.column);
+        let kind = format!("{:?}", token.kind);
+
+        writeln!(output, "{:<10}{:<6}{:<26}{}", position, token.span.length, kind, token.raw)?;
+    }
+
+    Ok(())
+}
+
+pub fn json(tokens: &[token::Token], output: &mut impl Write) -> Result<()> {
+    writeln!(output, "[")?;
+
+    for (index, token) in tokens.iter().enumerate() {
+        let separator = if index + 1 < tokens.len() { "," } else { "" };
+
+        writeln!(
+            output,
+            "  {{\"kind\": {}, \"raw\": {}, \"row\": {}, \"column\": {}, \"length\": {}}}{}",
+            json::string(&format!("{:?}", token.kind)),
+            json::string(&token.raw),
+            token.span.row,
+            token.span.column,
+            token.span.length,
+            separator
+        )?;
+    }
+
+    writeln!(output, "]")
+}
@@ -0,0 +1,21 @@
+pub const KEYWORDS: &[&str] = &[
+    "fn",
+    "let",
+    "if",
+    "else",
+    "while",
+    "for",
+    "in",
+    "break",
+    "continue",
+    "return",
+    "struct",
+    "enum",
+    "match",
+    "true",
+    "false"
+];
+
+pub fn is_keyword(name: &str) -> bool {
+    KEYWORDS.contains(&name)
+}
@@ -1,28 +1,47 @@
 use crate::utils::span::Span;
 use crate::text::token;
 use crate::diagnostic::diagnostic;
+use crate::diagnostic::suggestion;
+use crate::diagnostic::engine;
+use crate::diagnostic::message::Message;
+use crate::utils::log;
 
 pub struct Lexer {
     pub content: String,
     pub span: Span,
-    pub current: Option<u8>
+    pub current: Option<u8>,
+    pub trivia: bool,
+    pub pending: Vec<diagnostic::Diagnostic>
 }
 
 impl Lexer {
     pub fn new(stream: String, content: String) -> Self {
         Self {
+            current: content.as_bytes().first().copied(),
             content,
-            span: Span::new(stream.clone(), 1, 1, 0, stream.len()),
-            current: content.as_bytes().get(0).copied()
+            span: Span::new(stream, 1, 1, 0, 0),
+            trivia: false,
+            pending: Vec::new()
         }
     }
 
+    pub fn with_trivia(mut self) -> Self {
+        self.trivia = true;
+        self
+    }
+
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
+        if self.trivia && self.current.is_some_and(|c| c.is_ascii_whitespace()) {
+            return self.lex_whitespace();
+        }
+
         self.skip_whitespace();
 
         match self.current {
             Some(b'_') => self.lex_identifier(),
-            Some(b'.') => self.lex_number(),
+            Some(b'.') if self.peek().is_some_and(|c| c.is_ascii_digit()) && !self.after(b'.') => self.lex_number(),
+            Some(b'.') => self.lex_dot(),
             Some(b'"') => self.lex_string(),
             Some(b'\'') => self.lex_char(),
             Some(b'+') => self.lex_plus(),
@@ -41,6 +60,8 @@ impl Lexer {
             Some(b'(') | Some(b')') | Some(b'[') | Some(b']') | Some(b'{') | Some(b'}') => self.lex_bracket(),
             Some(b',') => self.lex_comma(),
             Some(b';') => self.lex_semicolon(),
+            Some(b':') => self.lex_colon(),
+            Some(b'#') => self.lex_hash(),
 
             Some(c) => {
                 if c.is_ascii_alphabetic() {
@@ -52,10 +73,41 @@ impl Lexer {
                 }
             },
 
-            None => Ok(token::Token::new(token::Kind::EndOfFile, self.span, String::from("end of file")))
+            None => Ok(token::Token::new(token::Kind::EndOfFile, self.span.clone(), String::from("end of file")))
         }
     }
 
+    pub fn tokenize(&mut self, engine: &mut engine::DiagnosticEngine) -> Vec<token::Token> {
+        let mut tokens = Vec::new();
+
+        while !engine.aborted() {
+            match self.next() {
+                Ok(token) => {
+                    log::debug!("lexer", "token {:?} {:?} at {}:{}", token.kind, token.raw, token.span.row, token.span.column);
+
+                    let end = token.kind == token::Kind::EndOfFile;
+                    tokens.push(token);
+
+                    if end {
+                        break;
+                    }
+                },
+
+                Err(diagnostic) => engine.report(diagnostic)
+            }
+
+            for diagnostic in self.pending.drain(..) {
+                engine.report(diagnostic);
+            }
+        }
+
+        tokens
+    }
+
+    fn after(&self, c: u8) -> bool {
+        self.span.index > 0 && self.content.as_bytes()[self.span.index - 1] == c
+    }
+
     fn lex_identifier(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
         let mut value = String::new();
@@ -78,15 +130,23 @@ impl Lexer {
         let mut span = self.span.clone();
         let mut value = String::new();
         let mut kind = token::Kind::Integer;
+        let mut stray = Vec::new();
+        let mut applicability = suggestion::Applicability::MachineApplicable;
 
         while self.current.is_some_and(|c| c.is_ascii_digit() || c == b'.') {
+            if self.current == Some(b'.') && self.peek().is_some_and(|c| c.is_ascii_alphabetic() || c == b'_' || c == b'.') {
+                break;
+            }
+
             if self.current == Some(b'.') {
                 if kind == token::Kind::Float {
-                    return Err(diagnostic::Diagnostic::new(
-                        diagnostic::Kind::SyntaxError,
-                        span,
-                        String::from("unexpected '.'")
-                    ));
+                    let mut dot = self.span.clone();
+                    dot.length = 1;
+                    stray.push(suggestion::Edit::remove(dot));
+
+                    if !value.ends_with(|c: char| c.is_ascii_digit()) || !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                        applicability = suggestion::Applicability::MaybeIncorrect;
+                    }
                 }
 
                 kind = token::Kind::Float;
@@ -98,33 +158,143 @@ impl Lexer {
 
         span.length = value.len();
 
-
-        if value.as_bytes().get(0) == Some(&b'.') && span.length == 1 {
-            Err(diagnostic::Diagnostic::new(
+        if let Some(first) = stray.first() {
+            self.pending.push(diagnostic::Diagnostic::new(
                 diagnostic::Kind::SyntaxError,
-                span,
-                String::from("unexpected '.'")
-            ))
-        } else {
-            Ok(token::Token::new(
-                kind,
-                span,
-                value
-            ))
+                first.span.clone(),
+                Message::new("lexer-unexpected-dot")
+            ).with_suggestion(suggestion::Suggestion::new(
+                Message::new("lexer-remove-extra-dot"),
+                stray,
+                applicability
+            )));
         }
+
+        Ok(token::Token::new(
+            kind,
+            span,
+            value
+        ))
     }
 
     fn lex_string(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
+        let mut span = self.span.clone();
+        self.advance();
+
+        loop {
+            match self.current {
+                Some(b'"') => break,
+
+                Some(b'\\') => {
+                    self.advance();
 
+                    if self.current.is_some_and(|c| c != b'\n') {
+                        self.advance();
+                    }
+                },
+
+                None | Some(b'\n') => {
+                    span.length = self.span.index - span.index;
+
+                    self.pending.push(diagnostic::Diagnostic::new(
+                        diagnostic::Kind::SyntaxError,
+                        span.clone(),
+                        Message::new("lexer-unterminated-string")
+                    ).with_suggestion(suggestion::Suggestion::new(
+                        Message::new("lexer-close-string"),
+                        vec![suggestion::Edit::insert(self.span.clone(), String::from("\""))],
+                        suggestion::Applicability::MaybeIncorrect
+                    )));
+
+                    return Ok(token::Token::new(
+                        token::Kind::String,
+                        span.clone(),
+                        String::from(&self.content[span.index..self.span.index])
+                    ));
+                },
+
+                Some(_) => self.advance()
+            }
+        }
+
+        self.advance();
+        span.length = self.span.index - span.index;
+
+        Ok(token::Token::new(
+            token::Kind::String,
+            span.clone(),
+            String::from(&self.content[span.index..self.span.index])
+        ))
     }
 
     fn lex_char(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
-        
+        let mut span = self.span.clone();
+        self.advance();
+
+        match self.current {
+            Some(b'\'') => {
+                self.advance();
+                span.length = 2;
+
+                self.pending.push(diagnostic::Diagnostic::new(
+                    diagnostic::Kind::SyntaxError,
+                    span.clone(),
+                    Message::new("lexer-empty-char")
+                ));
+
+                return Ok(token::Token::new(
+                    token::Kind::Char,
+                    span,
+                    String::from("''")
+                ));
+            },
+
+            Some(b'\\') => {
+                self.advance();
+
+                if self.current.is_some_and(|c| c != b'\n') {
+                    self.advance();
+                }
+            },
+
+            Some(b'\n') | None => (),
+
+            Some(_) => self.advance_character()
+        }
+
+        if self.current != Some(b'\'') {
+            span.length = self.span.index - span.index;
+
+            self.pending.push(diagnostic::Diagnostic::new(
+                diagnostic::Kind::SyntaxError,
+                span.clone(),
+                Message::new("lexer-unterminated-char")
+            ).with_suggestion(suggestion::Suggestion::new(
+                Message::new("lexer-close-char"),
+                vec![suggestion::Edit::insert(self.span.clone(), String::from("'"))],
+                suggestion::Applicability::MaybeIncorrect
+            )));
+
+            return Ok(token::Token::new(
+                token::Kind::Char,
+                span.clone(),
+                String::from(&self.content[span.index..self.span.index])
+            ));
+        }
+
+        self.advance();
+        span.length = self.span.index - span.index;
+
+        Ok(token::Token::new(
+            token::Kind::Char,
+            span.clone(),
+            String::from(&self.content[span.index..self.span.index])
+        ))
     }
 
     fn lex_plus(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -138,17 +308,17 @@ impl Lexer {
                 )))
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::Plus,
                 span,
                 String::from("+")
-            )))
+            ))
         }
     }
 
     fn lex_minus(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -172,17 +342,17 @@ impl Lexer {
                 )))
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
-                token::Kind::Plus,
+            _ => Ok(token::Token::new(
+                token::Kind::Minus,
                 span,
-                String::from("+")
-            )))
+                String::from("-")
+            ))
         }
     }
 
     fn lex_asterisk(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -196,17 +366,17 @@ impl Lexer {
                 )))
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::Asterisk,
                 span,
                 String::from("*")
-            )))
+            ))
         }
     }
 
     fn lex_slash(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -223,49 +393,77 @@ impl Lexer {
             Some(b'/') => {
                 while self.current.is_some_and(|c| c != b'\n') {
                     self.advance();
-                    span.length += 1;
                 }
 
-                self.next()
+                self.comment(span)
             },
 
             Some(b'*') => {
-                loop {
-                    if self.current == Some(b'*') {
-                        self.advance();
-
-                        if self.current.is_none() {
-                            return Err(diagnostic::Diagnostic::new(
-                                diagnostic::Kind::SyntaxError,
-                                span,
-                                String::from("unterminated sequence")
-                            ));
-                        }
+                span.length += 1;
+                self.advance();
 
-                        if self.current == Some(b'/') {
+                loop {
+                    match self.current {
+                        Some(b'*') if self.peek() == Some(b'/') => {
+                            self.advance();
                             self.advance();
                             break;
-                        }
-                    }
+                        },
 
-                    self.advance();
-                    span.length += 1;
+                        Some(_) => self.advance(),
+
+                        None => return Err(diagnostic::Diagnostic::new(
+                            diagnostic::Kind::SyntaxError,
+                            span,
+                            Message::new("lexer-unterminated-comment")
+                        ).with_suggestion(suggestion::Suggestion::new(
+                            Message::new("lexer-close-comment"),
+                            vec![suggestion::Edit::insert(self.span.clone(), String::from("*/"))],
+                            suggestion::Applicability::MachineApplicable
+                        )))
+                    }
                 }
 
-                self.next()
+                self.comment(span)
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::Slash,
                 span,
                 String::from("/")
-            )))
+            ))
         }
     }
 
+    fn lex_whitespace(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
+        let mut span = self.span.clone();
+        self.skip_whitespace();
+        span.length = self.span.index - span.index;
+
+        Ok(token::Token::new(
+            token::Kind::Whitespace,
+            span.clone(),
+            String::from(&self.content[span.index..self.span.index])
+        ))
+    }
+
+    fn comment(&mut self, mut span: Span) -> Result<token::Token, diagnostic::Diagnostic> {
+        if !self.trivia {
+            return self.next();
+        }
+
+        span.length = self.span.index - span.index;
+
+        Ok(token::Token::new(
+            token::Kind::Comment,
+            span.clone(),
+            String::from_utf8_lossy(&self.content.as_bytes()[span.index..self.span.index]).into_owned()
+        ))
+    }
+
     fn lex_modulo(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -279,17 +477,17 @@ impl Lexer {
                 )))
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::Modulo,
                 span,
                 String::from("%")
-            )))
+            ))
         }
     }
 
     fn lex_ampersand(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -313,17 +511,17 @@ impl Lexer {
                 )))
             }
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::Ampersand,
                 span,
                 String::from("&")
-            )))
+            ))
         }
     }
 
     fn lex_pipe(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -347,17 +545,17 @@ impl Lexer {
                 )))
             }
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::Pipe,
                 span,
                 String::from("|")
-            )))
+            ))
         }
     }
 
     fn lex_caret(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -371,18 +569,17 @@ impl Lexer {
                 )))
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::Caret,
                 span,
                 String::from("^")
-            )))
+            ))
         }
     }
 
     fn lex_tilde(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
-        self.advance();
+        span.length = 1;
 
         Ok(self.advance_with_token(token::Token::new(
             token::Kind::Tilde,
@@ -393,7 +590,7 @@ impl Lexer {
 
     fn lex_exclamation(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -407,17 +604,17 @@ impl Lexer {
                 )))
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::Exclamation,
                 span,
                 String::from("!")
-            )))
+            ))
         }
     }
 
     fn lex_greater_than(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -446,27 +643,25 @@ impl Lexer {
                         )))
                     },
 
-                    _ => {
-                        Ok(self.advance_with_token(token::Token::new(
-                            token::Kind::BitwiseRightShift,
-                            span,
-                            String::from(">>")
-                        )))
-                    }
+                    _ => Ok(token::Token::new(
+                        token::Kind::BitwiseRightShift,
+                        span,
+                        String::from(">>")
+                    ))
                 }
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::GreaterThan,
                 span,
-                String::from("!")
-            )))
+                String::from(">")
+            ))
         }
     }
 
     fn lex_less_than(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
@@ -495,33 +690,30 @@ impl Lexer {
                         )))
                     },
 
-                    _ => {
-                        Ok(self.advance_with_token(token::Token::new(
-                            token::Kind::BitwiseLeftShift,
-                            span,
-                            String::from("<<")
-                        )))
-                    }
+                    _ => Ok(token::Token::new(
+                        token::Kind::BitwiseLeftShift,
+                        span,
+                        String::from("<<")
+                    ))
                 }
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            _ => Ok(token::Token::new(
                 token::Kind::LessThan,
                 span,
                 String::from("<")
-            )))
+            ))
         }
     }
 
     fn lex_assign(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
-        span.length = 0;
+        span.length = 1;
         self.advance();
 
         match self.current {
             Some(b'=') => {
-                span = self.span.clone();
-                span.length = 2;
+                span.length += 1;
 
                 Ok(self.advance_with_token(token::Token::new(
                     token::Kind::AssignAssign,
@@ -530,11 +722,21 @@ impl Lexer {
                 )))
             },
 
-            None => Ok(self.advance_with_token(token::Token::new(
+            Some(b'>') => {
+                span.length += 1;
+
+                Ok(self.advance_with_token(token::Token::new(
+                    token::Kind::FatArrow,
+                    span,
+                    String::from("=>")
+                )))
+            },
+
+            _ => Ok(token::Token::new(
                 token::Kind::Assign,
                 span,
                 String::from("=")
-            )))
+            ))
         }
     }
 
@@ -578,6 +780,8 @@ impl Lexer {
                 span,
                 String::from("}")
             ))),
+
+            _ => self.lex_unhandled()
         }
     }
 
@@ -603,17 +807,64 @@ impl Lexer {
         )))
     }
 
-    fn lex_unhandled(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
+    fn lex_colon(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
+        let mut span = self.span.clone();
+        span.length = 1;
+        self.advance();
+
+        match self.current {
+            Some(b':') => {
+                span.length += 1;
+
+                Ok(self.advance_with_token(token::Token::new(
+                    token::Kind::ColonColon,
+                    span,
+                    String::from("::")
+                )))
+            },
+
+            _ => Ok(token::Token::new(
+                token::Kind::Colon,
+                span,
+                String::from(":")
+            ))
+        }
+    }
+
+    fn lex_dot(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
         let mut span = self.span.clone();
         span.length = 1;
 
         Ok(self.advance_with_token(token::Token::new(
-            token::Kind::Unhandled,
+            token::Kind::Dot,
             span,
-            String::from_utf8(vec![self.current.unwrap()]).unwrap()
+            String::from(".")
         )))
     }
 
+    fn lex_hash(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
+        let mut span = self.span.clone();
+        span.length = 1;
+
+        Ok(self.advance_with_token(token::Token::new(
+            token::Kind::Hash,
+            span,
+            String::from("#")
+        )))
+    }
+
+    fn lex_unhandled(&mut self) -> Result<token::Token, diagnostic::Diagnostic> {
+        let mut span = self.span.clone();
+        self.advance_character();
+        span.length = self.span.index - span.index;
+
+        Ok(token::Token::new(
+            token::Kind::Unhandled,
+            span.clone(),
+            String::from_utf8_lossy(&self.content.as_bytes()[span.index..self.span.index]).into_owned()
+        ))
+    }
+
     fn advance_with_token(&mut self, token: token::Token) -> token::Token {
         self.advance();
         token
@@ -621,19 +872,56 @@ impl Lexer {
 
     fn skip_whitespace(&mut self) {
         while self.current.is_some_and(|c| c.is_ascii_whitespace()) {
-            let current = self.current.clone();
-            if current == Some(b'\n') {
-                self.span.row += 1;
-                self.span.column = 0;
-            }
+            self.advance();
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.content.as_bytes().get(self.span.index + 1).copied()
+    }
 
+    fn advance_character(&mut self) {
+        self.advance();
+
+        while self.current.is_some_and(|c| c & 0b1100_0000 == 0b1000_0000) {
             self.advance();
         }
     }
 
     fn advance(&mut self) {
+        log::trace!("lexer", "advance from {}:{} over {:?}", self.span.row, self.span.column, self.current.map(char::from));
+
+        if self.current == Some(b'\n') {
+            self.span.row += 1;
+            self.span.column = 1;
+        } else {
+            self.span.column += 1;
+        }
+
         self.span.index += 1;
-        self.span.column += 1;
         self.current = self.content.as_bytes().get(self.span.index).copied();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::engine::DiagnosticEngine;
+    use crate::diagnostic::lint;
+    use crate::syntax::parser::Parser;
+    use crate::text::lexer::Lexer;
+
+    fn errors(content: &str) -> Vec<&'static str> {
+        let mut engine = DiagnosticEngine::quiet(lint::LintLevels::default());
+        let tokens = Lexer::new(String::from("test"), String::from(content)).tokenize(&mut engine);
+        Parser::new(&tokens).parse(&mut engine);
+
+        engine.diagnostics.iter().map(|diagnostic| diagnostic.message.id).collect()
+    }
+
+    #[test]
+    fn malformed_literals_do_not_cascade() {
+        assert_eq!(errors("fn main() {\n    let a = 1.2.3;\n}\n"), ["lexer-unexpected-dot"]);
+        assert_eq!(errors("fn main() {\n    let a = \"abc\n    ;\n}\n"), ["lexer-unterminated-string"]);
+        assert_eq!(errors("fn main() {\n    let a = '';\n}\n"), ["lexer-empty-char"]);
+    }
+}
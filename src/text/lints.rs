@@ -0,0 +1,164 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::engine::DiagnosticEngine;
+use crate::diagnostic::lint;
+use crate::diagnostic::message::Message;
+use crate::diagnostic::suggestion;
+use crate::syntax::ast::Ast;
+use crate::text::keyword;
+use crate::text::token;
+use crate::utils::similarity;
+
+fn scope_attributes(ast: &Ast, engine: &mut DiagnosticEngine) {
+    for attribute in &ast.attributes {
+        let Some(level) = lint::Level::from_name(&attribute.name) else {
+            engine.report(diagnostic::Diagnostic::new(
+                diagnostic::Kind::SyntaxError,
+                attribute.span.clone(),
+                Message::new("attribute-unknown").with("name", &attribute.name)
+            ).with_optional_help(lint::Level::did_you_mean(&attribute.name)));
+            continue;
+        };
+
+        for (name, span) in &attribute.arguments {
+            match lint::find(name) {
+                Some(found) => engine.lints.scope(lint::Scope {
+                    stream: span.stream.clone(),
+                    start: attribute.span.index,
+                    end: attribute.end,
+                    lint: found.name,
+                    level
+                }),
+
+                None => engine.report(diagnostic::Diagnostic::new(
+                    diagnostic::Kind::Lint,
+                    span.clone(),
+                    Message::new("lint-unknown").with("name", name)
+                ).with_lint(&lint::UNKNOWN_LINTS).with_optional_help(lint::did_you_mean(name)))
+            }
+        }
+    }
+}
+
+fn check_float(token: &token::Token, engine: &mut DiagnosticEngine) {
+    if token.raw.ends_with('.') {
+        let mut end = token.span.clone();
+        end.index += end.length;
+        end.column += end.length;
+
+        engine.report(diagnostic::Diagnostic::new(
+            diagnostic::Kind::Lint,
+            token.span.clone(),
+            Message::new("lint-trailing-dot-float")
+        ).with_lint(&lint::TRAILING_DOT_FLOAT).with_suggestion(suggestion::Suggestion::new(
+            Message::new("lint-add-trailing-zero"),
+            vec![suggestion::Edit::insert(end, String::from("0"))],
+            suggestion::Applicability::MachineApplicable
+        )));
+    }
+
+    if token.raw.starts_with('.') {
+        engine.report(diagnostic::Diagnostic::new(
+            diagnostic::Kind::Lint,
+            token.span.clone(),
+            Message::new("lint-leading-dot-float")
+        ).with_lint(&lint::LEADING_DOT_FLOAT).with_suggestion(suggestion::Suggestion::new(
+            Message::new("lint-add-leading-zero"),
+            vec![suggestion::Edit::insert(token.span.clone(), String::from("0"))],
+            suggestion::Applicability::MachineApplicable
+        )));
+    }
+}
+
+fn at_statement_start(previous: Option<&token::Token>) -> bool {
+    previous.is_none_or(|token| {
+        matches!(
+            token.kind,
+            token::Kind::SemiColon | token::Kind::LeftCurlyBrace | token::Kind::RightCurlyBrace | token::Kind::RightSquareBrace
+        )
+    })
+}
+
+fn cannot_continue(next: Option<&token::Token>) -> bool {
+    next.is_some_and(|token| {
+        matches!(
+            token.kind,
+            token::Kind::Identifier | token::Kind::Integer | token::Kind::Float | token::Kind::Boolean | token::Kind::String | token::Kind::Char
+        )
+    })
+}
+
+fn check_identifier(tokens: &[token::Token], index: usize, engine: &mut DiagnosticEngine) {
+    let token = &tokens[index];
+
+    if token.raw.len() < 4 || keyword::is_keyword(&token.raw) {
+        return;
+    }
+
+    if !at_statement_start(index.checked_sub(1).map(|previous| &tokens[previous])) || !cannot_continue(tokens.get(index + 1)) {
+        return;
+    }
+
+    let candidates = keyword::KEYWORDS.iter().filter(|keyword| keyword.len() >= token.raw.len());
+
+    let Some(keyword) = candidates.copied().find(|keyword| similarity::distance(&token.raw, keyword) == 1) else {
+        return;
+    };
+
+    engine.report(diagnostic::Diagnostic::new(
+        diagnostic::Kind::Lint,
+        token.span.clone(),
+        Message::new("lint-misspelled-keyword").with("name", &token.raw)
+    ).with_lint(&lint::MISSPELLED_KEYWORDS).with_help(Message::new("help-did-you-mean").with("name", keyword)));
+}
+
+pub fn check(tokens: &[token::Token], ast: &Ast, engine: &mut DiagnosticEngine) {
+    scope_attributes(ast, engine);
+
+    for (index, token) in tokens.iter().enumerate() {
+        match token.kind {
+            token::Kind::Float => check_float(token, engine),
+            token::Kind::Identifier => check_identifier(tokens, index, engine),
+            _ => ()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::diagnostic::Severity;
+    use crate::diagnostic::engine::DiagnosticEngine;
+    use crate::diagnostic::lint;
+    use crate::syntax::parser::Parser;
+    use crate::text::lexer::Lexer;
+
+    fn report(content: &str) -> Vec<(&'static str, Severity, usize)> {
+        let mut engine = DiagnosticEngine::quiet(lint::LintLevels::default());
+        let tokens = Lexer::new(String::from("test"), String::from(content)).tokenize(&mut engine);
+        let mut parser = Parser::new(&tokens);
+        parser.parse(&mut engine);
+        super::check(&tokens, &parser.ast, &mut engine);
+
+        engine.diagnostics.iter().map(|diagnostic| (diagnostic.message.id, diagnostic.severity, diagnostic.span.row)).collect()
+    }
+
+    #[test]
+    fn attributes_cover_the_next_item_or_statement() {
+        let content = "#[allow(trailing_dot_float)]\nfn a() {\n    let x = 1.;\n}\n\nfn b() {\n    #[deny(trailing_dot_float)]\n    let y = {\n        1.\n    };\n    let z = 2.;\n}\n";
+
+        assert_eq!(report(content), [
+            ("lint-trailing-dot-float", Severity::Error, 9),
+            ("lint-trailing-dot-float", Severity::Warning, 11)
+        ]);
+    }
+
+    #[test]
+    fn stray_and_malformed_attributes_are_reported() {
+        let content = "fn main() {\n    #\n    let a = 1;\n    #[allow(x\n    #[alow(unknown_lints)]\n    let b = 2;\n}\n";
+
+        assert_eq!(report(content), [
+            ("attribute-malformed", Severity::Error, 2),
+            ("attribute-malformed", Severity::Error, 4),
+            ("attribute-unknown", Severity::Error, 5)
+        ]);
+    }
+}
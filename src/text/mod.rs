@@ -1,2 +1,5 @@
 pub mod token;
+pub mod keyword;
 pub mod lexer;
+pub mod lints;
+pub mod dump;
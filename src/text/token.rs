@@ -1,6 +1,6 @@
 use crate::utils::span::Span;
 
-#[derive(Debug, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
 pub enum Kind {
     Identifier,
     Integer,
@@ -50,7 +50,15 @@ pub enum Kind {
 
     Comma,
     SemiColon,
+    Colon,
+    ColonColon,
+    Dot,
     Arrow,
+    FatArrow,
+    Hash,
+
+    Whitespace,
+    Comment,
 
     Unhandled,
     EndOfFile
@@ -63,6 +71,12 @@ pub struct Token {
     pub raw: String
 }
 
+impl Kind {
+    pub fn is_trivia(&self) -> bool {
+        matches!(self, Kind::Whitespace | Kind::Comment)
+    }
+}
+
 impl Token {
     pub fn new(kind: Kind, span: Span, raw: String) -> Self {
         Self {
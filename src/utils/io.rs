@@ -1,33 +1,147 @@
-use std::path::Path;
-use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::fs::{canonicalize, create_dir_all, read_link, remove_file, rename, symlink_metadata, write, File};
+use std::io::{Error, ErrorKind, Read};
+use std::sync::atomic::{AtomicU64, Ordering};
 use crate::diagnostic::diagnostic;
+use crate::diagnostic::message::Message;
 use crate::utils::span::Span;
 
+pub const DEFAULT_LIMIT: u64 = 64 * 1024 * 1024;
+
+static LIMIT: AtomicU64 = AtomicU64::new(DEFAULT_LIMIT);
+
+pub fn set_limit(limit: u64) {
+    LIMIT.store(limit, Ordering::Relaxed);
+}
+
+pub fn limit() -> u64 {
+    LIMIT.load(Ordering::Relaxed)
+}
+
+fn failure(kind: diagnostic::Kind, message: Message) -> diagnostic::Diagnostic {
+    diagnostic::Diagnostic::new(kind, Span::new(String::from("cli"), 1, 1, 0, 0), message)
+}
+
+fn reason(error: &Error) -> String {
+    let text = error.to_string();
+
+    match text.find(" (os error") {
+        Some(end) => String::from(&text[..end]),
+        None => text
+    }
+}
+
+fn open_failure(path: &Path, error: Error) -> diagnostic::Diagnostic {
+    let shown = path.display();
+
+    if error.kind() == ErrorKind::NotFound {
+        if let Ok(target) = symlink_metadata(path).and_then(|_| read_link(path)) {
+            return failure(
+                diagnostic::Kind::FileNotFound,
+                Message::new("io-broken-symlink").with("path", shown).with("target", target.display())
+            );
+        }
+
+        return failure(diagnostic::Kind::FileNotFound, Message::new("io-file-not-found").with("path", shown));
+    }
+
+    if error.kind() == ErrorKind::PermissionDenied {
+        return failure(diagnostic::Kind::FilePermissionDenied, Message::new("io-permission-denied").with("path", shown));
+    }
+
+    failure(
+        diagnostic::Kind::FileNotReadable,
+        Message::new("io-file-not-readable").with("path", shown).with("reason", reason(&error))
+    )
+}
+
+fn not_utf8(path: &str, bytes: &[u8], valid: usize) -> diagnostic::Diagnostic {
+    let prefix = String::from_utf8_lossy(&bytes[..valid]);
+    let row = prefix.matches('\n').count() + 1;
+    let column = prefix.rfind('\n').map(|newline| valid - newline).unwrap_or(valid + 1);
+
+    diagnostic::Diagnostic::new(
+        diagnostic::Kind::FileNotUtf8,
+        Span::new(String::from(path), row, column, valid, 1),
+        Message::new("io-not-utf8").with("path", path).with("offset", valid)
+    )
+}
+
 pub fn read_file(path: &String) -> Result<String, diagnostic::Diagnostic> {
     let absolute = Path::new(path);
+    let limit = limit();
 
-    if !absolute.exists() {
-        return Err(diagnostic::Diagnostic::new(
-            diagnostic::Kind::FileNotFound,
-            Span::new(String::from("cli"), 1, 1, 0, 0),
-            format!("could not find file: {}", absolute.file_name().unwrap().to_str().unwrap())
-        ));
-    };
-
-    if !absolute.is_file() {
-        return Err(diagnostic::Diagnostic::new(
-            diagnostic::Kind::FileNotRegular,
-            Span::new(String::from("cli"), 1, 1, 0, 0),
-            format!("file not regular: {}", absolute.file_name().unwrap().to_str().unwrap())
-        ));
-    };
-
-    match read_to_string(absolute) {
-        Ok(content) => Ok(content),
-        Err(_) => Err(diagnostic::Diagnostic::new(
-            diagnostic::Kind::FileNotReadable,
-            Span::new(String::from("cli"), 1, 1, 0, 0),
-            format!("file not readable: {}", absolute.file_name().unwrap().to_str().unwrap())
-        ))
+    let mut file = File::open(absolute).map_err(|error| open_failure(absolute, error))?;
+    let metadata = file.metadata().map_err(|error| open_failure(absolute, error))?;
+
+    if !metadata.is_file() {
+        return Err(failure(diagnostic::Kind::FileNotRegular, Message::new("io-file-not-regular").with("path", absolute.display())));
+    }
+
+    let too_large = |size: u64| failure(
+        diagnostic::Kind::FileTooLarge,
+        Message::new("io-file-too-large").with("path", absolute.display()).with("size", size).with("limit", limit)
+    );
+
+    if metadata.len() > limit {
+        return Err(too_large(metadata.len()));
+    }
+
+    let mut bytes = Vec::with_capacity(metadata.len() as usize);
+
+    if let Err(error) = (&mut file).take(limit + 1).read_to_end(&mut bytes) {
+        return Err(open_failure(absolute, error));
+    }
+
+    if bytes.len() as u64 > limit {
+        return Err(too_large(bytes.len() as u64));
     }
+
+    String::from_utf8(bytes).map_err(|error| {
+        let valid = error.utf8_error().valid_up_to();
+        not_utf8(path, error.as_bytes(), valid)
+    })
+}
+
+fn resolve(path: &Path) -> PathBuf {
+    match symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_symlink() => canonicalize(path).unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf()
+    }
+}
+
+pub fn write_file(path: &String, content: &str) -> Result<(), diagnostic::Diagnostic> {
+    let absolute = resolve(Path::new(path));
+    let temporary = absolute.with_extension("soyc-tmp");
+
+    let written = write(&temporary, content).and_then(|_| rename(&temporary, &absolute));
+
+    match written {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            let _ = remove_file(&temporary);
+
+            if error.kind() == ErrorKind::PermissionDenied {
+                return Err(failure(diagnostic::Kind::FilePermissionDenied, Message::new("io-permission-denied").with("path", path)));
+            }
+
+            Err(failure(
+                diagnostic::Kind::FileNotWritable,
+                Message::new("io-file-not-writable").with("path", path).with("reason", reason(&error))
+            ))
+        }
+    }
+}
+
+pub fn create_directory(path: &Path) -> Result<(), diagnostic::Diagnostic> {
+    create_dir_all(path).map_err(|error| {
+        if error.kind() == ErrorKind::PermissionDenied {
+            return failure(diagnostic::Kind::FilePermissionDenied, Message::new("io-permission-denied").with("path", path.display()));
+        }
+
+        failure(
+            diagnostic::Kind::FileNotWritable,
+            Message::new("io-directory-not-creatable").with("path", path.display()).with("reason", reason(&error))
+        )
+    })
 }
@@ -0,0 +1,135 @@
+use std::env::var;
+use std::fmt::Arguments;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace
+}
+
+pub const TARGETS: &[&str] = &["driver", "lexer", "parser", "diagnostic"];
+
+struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+    start: Instant
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+static MAX: AtomicU8 = AtomicU8::new(0);
+
+impl Level {
+    fn from_name(name: &str) -> Option<Option<Self>> {
+        match name {
+            "off" => Some(None),
+            "error" => Some(Some(Level::Error)),
+            "warn" => Some(Some(Level::Warn)),
+            "info" => Some(Some(Level::Info)),
+            "debug" => Some(Some(Level::Debug)),
+            "trace" => Some(Some(Level::Trace)),
+            _ => None
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE"
+        }
+    }
+}
+
+pub fn init(variable: &str) -> Vec<String> {
+    let mut filter = Filter {
+        default: None,
+        targets: Vec::new(),
+        start: Instant::now()
+    };
+
+    let mut invalid = Vec::new();
+    let directives = var(variable).unwrap_or_default();
+
+    for directive in directives.split(',').map(str::trim).filter(|directive| !directive.is_empty()) {
+        match directive.split_once('=') {
+            Some((target, level)) if TARGETS.contains(&target) => match Level::from_name(level) {
+                Some(level) => filter.targets.push((String::from(target), level)),
+                None => invalid.push(String::from(directive))
+            },
+
+            Some(_) => invalid.push(String::from(directive)),
+
+            None => match Level::from_name(directive) {
+                Some(level) => filter.default = level,
+                None if TARGETS.contains(&directive) => filter.targets.push((String::from(directive), Some(Level::Trace))),
+                None => invalid.push(String::from(directive))
+            }
+        }
+    }
+
+    let max = filter.targets.iter().map(|(_, level)| *level).chain([filter.default]).flatten().max();
+    MAX.store(max.map(|level| level as u8).unwrap_or(0), Ordering::Relaxed);
+
+    let _ = FILTER.set(filter);
+    invalid
+}
+
+pub fn enabled(target: &str, level: Level) -> bool {
+    if level as u8 > MAX.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    let Some(filter) = FILTER.get() else {
+        return false;
+    };
+
+    let allowed = filter.targets
+        .iter()
+        .rev()
+        .find(|(name, _)| name == target)
+        .map(|(_, level)| *level)
+        .unwrap_or(filter.default);
+
+    allowed.is_some_and(|allowed| level <= allowed)
+}
+
+pub fn write(target: &str, level: Level, arguments: Arguments<'_>) {
+    let elapsed = FILTER.get().map(|filter| filter.start.elapsed().as_secs_f64()).unwrap_or(0.0);
+    eprintln!("[{:>9.6}s {:<5} {}] {}", elapsed, level.label(), target, arguments);
+}
+
+macro_rules! log {
+    ($target:expr, $level:expr, $($arguments:tt)+) => {
+        if $crate::utils::log::enabled($target, $level) {
+            $crate::utils::log::write($target, $level, format_args!($($arguments)+));
+        }
+    };
+}
+
+macro_rules! trace {
+    ($target:expr, $($arguments:tt)+) => {
+        $crate::utils::log::log!($target, $crate::utils::log::Level::Trace, $($arguments)+)
+    };
+}
+
+macro_rules! debug {
+    ($target:expr, $($arguments:tt)+) => {
+        $crate::utils::log::log!($target, $crate::utils::log::Level::Debug, $($arguments)+)
+    };
+}
+
+macro_rules! info {
+    ($target:expr, $($arguments:tt)+) => {
+        $crate::utils::log::log!($target, $crate::utils::log::Level::Info, $($arguments)+)
+    };
+}
+
+pub(crate) use {log, trace, debug, info};
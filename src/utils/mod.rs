@@ -1,2 +1,7 @@
 pub mod io;
 pub mod span;
+pub mod similarity;
+pub mod json;
+pub mod toml;
+pub mod alloc;
+pub mod log;
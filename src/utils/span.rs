@@ -1,4 +1,4 @@
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct Span {
     pub stream: String,
     pub row: usize,
@@ -17,4 +17,10 @@ impl Span {
             length
         }
     }
+
+    pub fn to(&self, end: &Span) -> Self {
+        let mut span = self.clone();
+        span.length = (end.index + end.length).saturating_sub(self.index);
+        span
+    }
 }
@@ -0,0 +1,371 @@
+use crate::diagnostic::diagnostic;
+use crate::diagnostic::message::Message;
+use crate::utils::span::Span;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<Item>),
+    Table(Table)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub value: Value,
+    pub span: Span
+}
+
+pub type Table = BTreeMap<String, Item>;
+
+impl Value {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Value::String(_) => "string",
+            Value::Integer(_) => "integer",
+            Value::Boolean(_) => "boolean",
+            Value::Array(_) => "array",
+            Value::Table(_) => "table"
+        }
+    }
+}
+
+struct Parser<'a> {
+    content: &'a str,
+    span: Span
+}
+
+impl<'a> Parser<'a> {
+    fn current(&self) -> Option<u8> {
+        self.content.as_bytes().get(self.span.index).copied()
+    }
+
+    fn advance(&mut self) {
+        if self.current() == Some(b'\n') {
+            self.span.row += 1;
+            self.span.column = 1;
+        } else {
+            self.span.column += 1;
+        }
+
+        self.span.index += 1;
+    }
+
+    fn here(&self, length: usize) -> Span {
+        let mut span = self.span.clone();
+        span.length = length;
+        span
+    }
+
+    fn error(&self, message: Message) -> diagnostic::Diagnostic {
+        diagnostic::Diagnostic::new(diagnostic::Kind::ManifestError, self.here(1), message)
+    }
+
+    fn unexpected(&self, expected: &str) -> diagnostic::Diagnostic {
+        let found = match self.current() {
+            Some(b'\n') | None => String::from("end of line"),
+            Some(_) => self.content[self.span.index..].chars().next().map(String::from).unwrap_or_default()
+        };
+
+        self.error(Message::new("toml-unexpected").with("found", found).with("expected", expected))
+    }
+
+    fn skip_spaces(&mut self) {
+        while matches!(self.current(), Some(b' ') | Some(b'\t') | Some(b'\r')) {
+            self.advance();
+        }
+    }
+
+    fn skip_comment(&mut self) {
+        if self.current() == Some(b'#') {
+            while self.current().is_some_and(|c| c != b'\n') {
+                self.advance();
+            }
+        }
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            self.skip_spaces();
+            self.skip_comment();
+
+            match self.current() {
+                Some(b'\n') => self.advance(),
+                _ => break
+            }
+        }
+    }
+
+    fn expect(&mut self, byte: u8, expected: &str) -> Result<(), diagnostic::Diagnostic> {
+        if self.current() != Some(byte) {
+            return Err(self.unexpected(expected));
+        }
+
+        self.advance();
+        Ok(())
+    }
+
+    fn end_of_line(&mut self) -> Result<(), diagnostic::Diagnostic> {
+        self.skip_spaces();
+        self.skip_comment();
+
+        match self.current() {
+            None => Ok(()),
+            Some(b'\n') => {
+                self.advance();
+                Ok(())
+            },
+            Some(_) => Err(self.unexpected("end of line"))
+        }
+    }
+
+    fn key(&mut self) -> Result<Vec<(String, Span)>, diagnostic::Diagnostic> {
+        let mut keys = Vec::new();
+
+        loop {
+            self.skip_spaces();
+            let start = self.span.clone();
+
+            let key = match self.current() {
+                Some(b'"') | Some(b'\'') => self.string()?,
+                _ => {
+                    while self.current().is_some_and(|c| c.is_ascii_alphanumeric() || c == b'_' || c == b'-') {
+                        self.advance();
+                    }
+
+                    if self.span.index == start.index {
+                        return Err(self.unexpected("a key"));
+                    }
+
+                    String::from(&self.content[start.index..self.span.index])
+                }
+            };
+
+            let mut span = start;
+            span.length = self.span.index - span.index;
+            keys.push((key, span));
+
+            self.skip_spaces();
+
+            if self.current() != Some(b'.') {
+                return Ok(keys);
+            }
+
+            self.advance();
+        }
+    }
+
+    fn string(&mut self) -> Result<String, diagnostic::Diagnostic> {
+        let quote = self.current().unwrap_or(b'"');
+        let start = self.here(1);
+        let mut value = String::new();
+
+        self.advance();
+
+        loop {
+            match self.current() {
+                None | Some(b'\n') => return Err(diagnostic::Diagnostic::new(
+                    diagnostic::Kind::ManifestError,
+                    start,
+                    Message::new("toml-unterminated-string")
+                )),
+
+                Some(c) if c == quote => {
+                    self.advance();
+                    return Ok(value);
+                },
+
+                Some(b'\\') if quote == b'"' => {
+                    self.advance();
+
+                    let escaped = match self.current() {
+                        Some(b'n') => '\n',
+                        Some(b't') => '\t',
+                        Some(b'r') => '\r',
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        _ => return Err(self.unexpected("an escape sequence"))
+                    };
+
+                    value.push(escaped);
+                    self.advance();
+                },
+
+                Some(_) => {
+                    let c = self.content[self.span.index..].chars().next().unwrap_or_default();
+                    value.push(c);
+
+                    for _ in 0..c.len_utf8() {
+                        self.advance();
+                    }
+                }
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Item, diagnostic::Diagnostic> {
+        self.skip_spaces();
+        let start = self.span.clone();
+
+        let value = match self.current() {
+            Some(b'"') | Some(b'\'') => Value::String(self.string()?),
+
+            Some(b'[') => {
+                self.advance();
+                let mut items = Vec::new();
+
+                loop {
+                    self.skip_trivia();
+
+                    if self.current() == Some(b']') {
+                        self.advance();
+                        break;
+                    }
+
+                    items.push(self.value()?);
+                    self.skip_trivia();
+
+                    match self.current() {
+                        Some(b',') => self.advance(),
+                        Some(b']') => (),
+                        _ => return Err(self.unexpected("',' or ']'"))
+                    }
+                }
+
+                Value::Array(items)
+            },
+
+            Some(c) if c.is_ascii_alphanumeric() || c == b'-' || c == b'+' => {
+                while self.current().is_some_and(|c| c.is_ascii_alphanumeric() || c == b'_' || c == b'-' || c == b'+' || c == b'.') {
+                    self.advance();
+                }
+
+                let word = &self.content[start.index..self.span.index];
+
+                match word {
+                    "true" => Value::Boolean(true),
+                    "false" => Value::Boolean(false),
+                    _ => match word.replace('_', "").parse::<i64>() {
+                        Ok(integer) => Value::Integer(integer),
+                        Err(_) => {
+                            let mut span = start;
+                            span.length = word.len();
+
+                            return Err(diagnostic::Diagnostic::new(
+                                diagnostic::Kind::ManifestError,
+                                span,
+                                Message::new("toml-unsupported-value").with("value", word)
+                            ));
+                        }
+                    }
+                }
+            },
+
+            _ => return Err(self.unexpected("a value"))
+        };
+
+        let mut span = start;
+        span.length = self.span.index - span.index;
+
+        Ok(Item {
+            value,
+            span
+        })
+    }
+}
+
+fn table<'t>(root: &'t mut Table, path: &[(String, Span)]) -> Result<&'t mut Table, diagnostic::Diagnostic> {
+    let mut current = root;
+
+    for (key, span) in path {
+        let item = current.entry(key.clone()).or_insert_with(|| Item {
+            value: Value::Table(Table::new()),
+            span: span.clone()
+        });
+
+        current = match &mut item.value {
+            Value::Table(table) => table,
+            _ => return Err(diagnostic::Diagnostic::new(
+                diagnostic::Kind::ManifestError,
+                span.clone(),
+                Message::new("toml-duplicate-key").with("key", key)
+            ))
+        };
+    }
+
+    Ok(current)
+}
+
+pub fn parse(stream: &str, content: &str) -> Result<Table, diagnostic::Diagnostic> {
+    let mut parser = Parser {
+        content,
+        span: Span::new(String::from(stream), 1, 1, 0, 0)
+    };
+
+    let mut root = Table::new();
+    let mut headers: Vec<Vec<String>> = Vec::new();
+    let mut path = Vec::new();
+
+    loop {
+        parser.skip_trivia();
+
+        match parser.current() {
+            None => return Ok(root),
+
+            Some(b'[') => {
+                let start = parser.here(1);
+                parser.advance();
+
+                if parser.current() == Some(b'[') {
+                    return Err(diagnostic::Diagnostic::new(
+                        diagnostic::Kind::ManifestError,
+                        start,
+                        Message::new("toml-unsupported-value").with("value", "[[...]]")
+                    ));
+                }
+
+                path = parser.key()?;
+                parser.expect(b']', "']'")?;
+                parser.end_of_line()?;
+
+                let names = path.iter().map(|(key, _)| key.clone()).collect::<Vec<_>>();
+
+                if headers.contains(&names) {
+                    return Err(diagnostic::Diagnostic::new(
+                        diagnostic::Kind::ManifestError,
+                        start,
+                        Message::new("toml-duplicate-key").with("key", names.join("."))
+                    ));
+                }
+
+                headers.push(names);
+                table(&mut root, &path)?;
+            },
+
+            Some(_) => {
+                let mut keys = parser.key()?;
+                parser.expect(b'=', "'='")?;
+                let item = parser.value()?;
+                parser.end_of_line()?;
+
+                let (key, span) = keys.pop().unwrap_or_default();
+                let mut full = path.clone();
+                full.extend(keys);
+
+                let table = table(&mut root, &full)?;
+
+                if table.contains_key(&key) {
+                    return Err(diagnostic::Diagnostic::new(
+                        diagnostic::Kind::ManifestError,
+                        span,
+                        Message::new("toml-duplicate-key").with("key", key)
+                    ));
+                }
+
+                table.insert(key, item);
+            }
+        }
+    }
+}